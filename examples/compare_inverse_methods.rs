@@ -0,0 +1,146 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Compare Inverse Methods - Every way to map points back through a pose
+//
+// Q: What are the options?
+// A: Invert the Isometry3 (conjugate the quaternion, rotate the negated
+//    translation), skip building the inverse with inverse_transform_point,
+//    invert an IsometryMatrix3 (a transpose), or go general: try_inverse on
+//    the homogeneous Matrix4, or on a Transform3 wrapping it.
+//
+// Q: Which to use?
+// A: Timed below, both the inverse itself and mapping a batch of points with
+//    it (the inverse built once per batch, inverse_transform_point paying
+//    per point instead). The general inverses are the slow ones, and the
+//    only ones that can fail.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::{format_ns_per_op, time};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    let iso = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.2, -0.5));
+    let isom = IsometryMatrix3::from_parts(iso.translation, iso.rotation.to_rotation_matrix());
+    let matrix = iso.to_homogeneous();
+    let transform = Transform3::from_matrix_unchecked(matrix);
+    let points: Vec<Point3> = (0..1000)
+        .map(|i| Point3::new(i as f64 * 0.01, 1.0, -1.0))
+        .collect();
+
+    println!("Inverse Methods - Agreement");
+    let mut worst: f64 = 0.0;
+    for p in &points {
+        let expected = iso.inverse() * p;
+        for actual in [
+            iso.inverse_transform_point(p),
+            isom.inverse() * p,
+            matrix.try_inverse().unwrap().transform_point(p),
+            transform.try_inverse().unwrap().transform_point(p),
+        ] {
+            worst = worst.max((actual - expected).norm());
+        }
+    }
+    println!(" - worst difference from Isometry3::inverse: {:e}", worst);
+    assert!(worst < 1e-12);
+
+    println!("Inverse Methods - Timing");
+    let inverses = 10_000_000;
+    let repetitions = 10_000;
+    let points_mapped = repetitions * points.len();
+    let rows = [
+        (
+            "Isometry3::inverse",
+            time(inverses, |_| {
+                let _ = black_box(black_box(iso).inverse());
+            }),
+            time(repetitions, |_| {
+                let inverse = black_box(iso).inverse();
+                for p in black_box(&points) {
+                    let _ = black_box(inverse * p);
+                }
+            }),
+        ),
+        (
+            "Isometry3::inverse_transform_point",
+            // nothing to build up front, the row's inverse column is n/a
+            Default::default(),
+            time(repetitions, |_| {
+                let iso = black_box(iso);
+                for p in black_box(&points) {
+                    let _ = black_box(iso.inverse_transform_point(p));
+                }
+            }),
+        ),
+        (
+            "IsometryMatrix3::inverse",
+            time(inverses, |_| {
+                let _ = black_box(black_box(isom).inverse());
+            }),
+            time(repetitions, |_| {
+                let inverse = black_box(isom).inverse();
+                for p in black_box(&points) {
+                    let _ = black_box(inverse * p);
+                }
+            }),
+        ),
+        (
+            "Matrix4::try_inverse",
+            time(inverses, |_| {
+                let _ = black_box(black_box(matrix).try_inverse());
+            }),
+            time(repetitions, |_| {
+                if let Some(inverse) = black_box(matrix).try_inverse() {
+                    for p in black_box(&points) {
+                        let _ = black_box(inverse.transform_point(p));
+                    }
+                }
+            }),
+        ),
+        (
+            "Transform3::try_inverse",
+            time(inverses, |_| {
+                let _ = black_box(black_box(transform).try_inverse());
+            }),
+            time(repetitions, |_| {
+                if let Some(inverse) = black_box(transform).try_inverse() {
+                    for p in black_box(&points) {
+                        let _ = black_box(inverse.transform_point(p));
+                    }
+                }
+            }),
+        ),
+    ];
+    println!(
+        " {:<36} {:>16} {:>16}",
+        "method", "ns per inverse", "ns per point"
+    );
+    for (name, inverse, mapping) in rows {
+        println!(
+            " {:<36} {:>16} {:>16}",
+            name,
+            format_ns_per_op(inverse, inverses),
+            format_ns_per_op(mapping, points_mapped)
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,84 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Conventions - Which way round does the multiply go?
+//
+// Name transforms a_T_b, read "the pose of frame b expressed in frame a". It
+// maps points expressed in b into a, so the inner frame names of a product
+// have to match and they cancel:
+//
+//   world_T_robot * robot_T_sensor = world_T_sensor
+//   world_T_sensor * sensor_p      = world_p
+//
+// If the inner names don't match, the multiply is a bug. This is the classic
+// inverted-transform bug, it compiles and runs, it just gives you the wrong
+// answer.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use approx::assert_relative_eq;
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // The robot sits at (2, 0, 0) in the world, turned 90 degrees to the left
+    let world_t_robot = Isometry3::new(
+        Vector3::new(2.0, 0.0, 0.0),
+        Vector3::z() * std::f64::consts::FRAC_PI_2,
+    );
+    // The sensor is bolted 0.5m ahead of and 0.2m above the robot's origin
+    let robot_t_sensor = Isometry3::translation(0.5, 0.0, 0.2);
+    // The sensor sees something 1m straight ahead of it
+    let sensor_p = Point3::new(1.0, 0.0, 0.0);
+
+    println!("Conventions - Chaining");
+    // robot_T_sensor * sensor_p = robot_p = (1.5, 0, 0.2), the robot is
+    // facing +y in the world, so that's 1.5m along +y from (2, 0, 0)
+    let world_t_sensor = world_t_robot * robot_t_sensor;
+    let world_p = world_t_sensor * sensor_p;
+    println!(
+        " - world_T_robot * robot_T_sensor * sensor_p: {:?}",
+        world_p
+    );
+    assert_relative_eq!(world_p, Point3::new(2.0, 1.5, 0.2), epsilon = 1e-12);
+
+    println!("Conventions - Wrong Way Round");
+    // robot_T_sensor * world_T_robot - 'sensor' and 'world' don't cancel
+    let wrong_p = robot_t_sensor * world_t_robot * sensor_p;
+    println!(
+        " - robot_T_sensor * world_T_robot * sensor_p: {:?}",
+        wrong_p
+    );
+    assert_relative_eq!(wrong_p, Point3::new(2.5, 1.0, 0.2), epsilon = 1e-12);
+
+    println!("Conventions - Going Back Down the Chain");
+    // robot_T_world = world_T_robot^-1, world_T_robot * world_p would be
+    // another silent bug ('robot' and 'world' don't cancel)
+    let robot_t_world = world_t_robot.inverse();
+    let robot_p = robot_t_world * world_p;
+    println!(" - world_T_robot.inverse() * world_p: {:?}", robot_p);
+    assert_relative_eq!(robot_p, Point3::new(1.5, 0.0, 0.2), epsilon = 1e-12);
+    assert_relative_eq!(
+        robot_p,
+        world_t_robot.inverse_transform_point(&world_p),
+        epsilon = 1e-12
+    );
+    println!(
+        " - world_T_robot * world_p (bug): {:?}",
+        world_t_robot * world_p
+    );
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,132 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! FMA - Does fused multiply-add speed up the transform math?
+//
+// Build it both ways and compare:
+//
+//   cargo run --release --example fma
+//   RUSTFLAGS="-C target-feature=+fma" cargo run --release --example fma
+//
+// (or -C target-cpu=native, which turns on whatever the machine has).
+//
+// Q: Does enabling FMA make nalgebra faster?
+// A: Not by itself. rustc never contracts a * b + c into an FMA, as that
+//    changes the rounding, so ordinary arithmetic keeps its separate
+//    multiplies and adds. Watch out though, on x86_64 +fma implies AVX, and
+//    the wider vectors alone can change the picture a lot (see the Matrix4
+//    multiply), that's not FMA at work.
+//
+// Q: So what changes?
+// A: f64::mul_add. With the target feature it's a single instruction, without
+//    it's a call to a correctly rounded software fallback, which is far
+//    slower. Code written with mul_add wants the feature, code without it
+//    barely notices.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::{format_ns_per_op, time};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Matrix4 = nalgebra::base::Matrix4<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Applies the top three rows of a homogeneous transform to a point, plain
+/// multiplies and adds.
+fn affine_separate(m: &Matrix4, p: &Point3) -> Point3 {
+    Point3::from(Vector3::from_fn(|row, _| {
+        m[(row, 0)] * p.x + m[(row, 1)] * p.y + m[(row, 2)] * p.z + m[(row, 3)]
+    }))
+}
+
+/// As [`affine_separate`], but fused.
+fn affine_fused(m: &Matrix4, p: &Point3) -> Point3 {
+    Point3::from(Vector3::from_fn(|row, _| {
+        m[(row, 0)].mul_add(
+            p.x,
+            m[(row, 1)].mul_add(p.y, m[(row, 2)].mul_add(p.z, m[(row, 3)])),
+        )
+    }))
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    println!("FMA - Availability");
+    println!(
+        " - compiled with the fma target feature: {}",
+        cfg!(target_feature = "fma")
+    );
+    #[cfg(target_arch = "x86_64")]
+    println!(
+        " - this cpu supports fma: {}",
+        std::arch::is_x86_feature_detected!("fma")
+    );
+
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let matrix = iso.to_homogeneous();
+    let p = Point3::new(0.5, -1.5, 2.5);
+    // fused rounds once instead of twice, so may differ in the last bits
+    println!(
+        " - separate vs fused difference: {:e}",
+        (affine_separate(&matrix, &p) - affine_fused(&matrix, &p)).norm()
+    );
+
+    println!("FMA - Timing");
+    let sub_samples = 10000000;
+    let durations = [
+        (
+            "Isometry*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(iso) * black_box(p));
+            }),
+        ),
+        (
+            "Matrix4*homogeneous point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(matrix) * black_box(p).to_homogeneous());
+            }),
+        ),
+        (
+            "Affine multiply and add",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(affine_separate(&black_box(matrix), &black_box(p)));
+            }),
+        ),
+        (
+            "Affine mul_add",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(affine_fused(&black_box(matrix), &black_box(p)));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            " - {} took {} seconds, {} ns per point",
+            name,
+            duration.as_secs_f64(),
+            format_ns_per_op(duration, sub_samples)
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
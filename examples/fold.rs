@@ -0,0 +1,103 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Fold - Compose the chain first, then transform
+//
+// Q: A point has to go through a chain of N transforms (e.g. up a robot's
+//    kinematic tree), which is cheaper?
+// A: For one point, stepping through (a composition costs more than a point
+//    transform). For many, fold the chain into a single transform once, then
+//    apply that to each point: N compositions plus one transform per point,
+//    instead of N transforms per point.
+//
+// Q: Any catch?
+// A: The results differ in the last bits, the rounding happens in a
+//    different order, so compare them with a tolerance.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::{format_ns_per_op, time};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // A chain of N links, and a cloud of points at the end of it
+    let n = 16;
+    let chain: Vec<Isometry3> = (0..n)
+        .map(|i| {
+            Isometry3::new(
+                Vector3::new(0.3, 0.0, 0.1),
+                Vector3::new(0.0, 0.1, 0.05 * i as f64),
+            )
+        })
+        .collect();
+    let points: Vec<Point3> = (0..100_000)
+        .map(|i| Point3::new(i as f64 * 1e-5, 1.0, -1.0))
+        .collect();
+
+    // world_T_link0 * link0_T_link1 * ... applies the last link first
+    let step_by_step = |p: &Point3| chain.iter().rev().fold(*p, |p, link| link * p);
+    let fold = || {
+        chain
+            .iter()
+            .fold(Isometry3::identity(), |composed, link| composed * link)
+    };
+
+    println!("Fold - Agreement ({} links)", n);
+    let composed = fold();
+    let worst = points
+        .iter()
+        .map(|p| (step_by_step(p) - composed * p).norm())
+        .fold(0.0, f64::max);
+    println!(" - worst difference: {:e}", worst);
+    assert!(worst < 1e-12);
+
+    println!("Fold - Timing ({} links, {} points)", n, points.len());
+    let repetitions = 20;
+    let ops = repetitions * points.len();
+    let durations = [
+        (
+            "link by link",
+            time(repetitions, |_| {
+                for p in black_box(&points) {
+                    let _ = black_box(chain.iter().rev().fold(*p, |p, link| black_box(link) * p));
+                }
+            }),
+        ),
+        (
+            "folded first",
+            time(repetitions, |_| {
+                let composed = chain.iter().fold(Isometry3::identity(), |composed, link| {
+                    composed * black_box(link)
+                });
+                for p in black_box(&points) {
+                    let _ = black_box(composed * p);
+                }
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            " - {} took {} seconds, {} ns per point",
+            name,
+            duration.as_secs_f64(),
+            format_ns_per_op(duration, ops)
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
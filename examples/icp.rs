@@ -0,0 +1,118 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! ICP - Iterative Closest Point, registering two point clouds
+//
+// Q: How does it work?
+// A: Guess which target point each source point corresponds to (its nearest
+//    neighbour), solve for the rigid transform that best maps the pairs
+//    (align), apply it, repeat. Each iteration improves the guess, as long as
+//    the clouds start roughly registered.
+//
+// Q: Where does the time go?
+// A: Correspondence, brute force nearest neighbours is O(n * m), the
+//    alignment is O(n). Real implementations use a k-d tree for the former.
+//
+// This runs a single iteration.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_examples::bench::{format_ns_per_op, time};
+use rust_examples::transforms::align;
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Nearest target point to each source point, by brute force.
+fn correspondences(source: &[Point3], target: &[Point3]) -> Vec<Point3> {
+    source
+        .iter()
+        .map(|p| {
+            *target
+                .iter()
+                .min_by(|a, b| (*a - p).norm_squared().total_cmp(&(*b - p).norm_squared()))
+                .expect("target cloud is empty")
+        })
+        .collect()
+}
+
+/// RMS distance from each source point to its nearest target point.
+fn residual(source: &[Point3], target: &[Point3]) -> f64 {
+    let matched = correspondences(source, target);
+    let sum: f64 = source
+        .iter()
+        .zip(&matched)
+        .map(|(s, t)| (t - s).norm_squared())
+        .sum();
+    (sum / source.len() as f64).sqrt()
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // A noisy cloud, and the same cloud slightly moved
+    let mut rng = StdRng::seed_from_u64(0);
+    let target: Vec<Point3> = (0..500)
+        .map(|_| Point3::from(Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0))))
+        .collect();
+    let target_t_source = Isometry3::new(
+        Vector3::new(0.05, -0.02, 0.03),
+        Vector3::new(0.02, -0.01, 0.04),
+    );
+    let source: Vec<Point3> = target
+        .iter()
+        .map(|p| {
+            let noise = Vector3::from_fn(|_, _| rng.gen_range(-0.005..0.005));
+            target_t_source.inverse_transform_point(p) + noise
+        })
+        .collect();
+
+    println!("ICP - One Iteration");
+    let matched = correspondences(&source, &target);
+    let estimate = align(&source, &matched);
+    let moved: Vec<Point3> = source.iter().map(|p| estimate * p).collect();
+    println!(" - residual before: {:.4}", residual(&source, &target));
+    println!(" - residual after: {:.4}", residual(&moved, &target));
+    println!(
+        " - translation error: {:.4}, rotation error: {:.4} rad",
+        (estimate.translation.vector - target_t_source.translation.vector).norm(),
+        estimate.rotation.angle_to(&target_t_source.rotation)
+    );
+
+    println!("ICP - Timing");
+    let sub_samples = 100;
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(correspondences(black_box(&source), black_box(&target)));
+    });
+    println!(
+        " - correspondence took {} seconds, {} ns per iteration",
+        duration.as_secs_f64(),
+        format_ns_per_op(duration, sub_samples)
+    );
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(align(black_box(&source), black_box(&matched)));
+    });
+    println!(
+        " - alignment took {} seconds, {} ns per iteration",
+        duration.as_secs_f64(),
+        format_ns_per_op(duration, sub_samples)
+    );
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
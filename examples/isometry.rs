@@ -4,29 +4,1886 @@
 
 //! Isometry3 - How performant is it?
 //
+// Flags:
+//   --sub-samples <n> : samples per representation (default 10M)
+//   --sweep         : report time per op across a range of sub_samples
+//   --duration <s>  : run each representation for a fixed wall-clock time
+//                     instead of a fixed count, and report ops per second
+//   --verify        : check the selected representations compose, invert and
+//                     transform alike on every sample first
+//   --strict        : check every generated quaternion is normalised and every
+//                     rotation matrix orthonormal before timing anything
+//   --unit <unit>   : report times in ns, us, ms or s (default auto, whichever
+//                     reads best)
+//   --only <names>  : comma separated representations to time, any of
+//                     transform, iso, isomat, sim (default all)
+//   --pin-core <id> : pin the measuring thread to a core, needs the pin-core
+//                     feature, a no-op otherwise or on unsupported platforms
+//   --list          : list the representations and flags, then exit
+//   --criterion-json <dir> : also write the headline results in criterion's
+//                     <dir>/<group>/<name>/new/estimates.json layout, not
+//                     with --sweep or --duration, which have none to write
+//   --verbose       : print poses in full (nalgebra's Debug) as well as in
+//                     the compact pos / rpy form, and log the first few
+//                     samples of each representation one at a time
+//                     (RUST_LOG=debug)
+//
 // ***************************************************************************
 // Dependencies
 // ***************************************************************************
 
 use std::hint::black_box;
+use std::time::Duration;
+
+use approx::relative_eq;
+use log::{debug, info, warn};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use rust_examples::bench::{
+    format_time, format_time_per_op, ns_per_op, pin_to_core, ratios_to_fastest, time,
+    write_criterion_estimates, Unit,
+};
+use rust_examples::headline::{run, verify, BenchResult, Config, REGISTRY};
+use rust_examples::transforms::{
+    adjoint, average, box_minus, box_plus, format_pose, from_pose_euler, isometry_try_from_matrix,
+    look_at, nearest_isometry, rotate_about, to_relative, weighted_average, QuantizedPose,
+};
 
 type Point3 = nalgebra::geometry::Point3<f64>;
 type Translation3 = nalgebra::geometry::Translation3<f64>;
 type Isometry3 = nalgebra::geometry::Isometry3<f64>;
 type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Similarity3 = nalgebra::geometry::Similarity3<f64>;
 type Rotation3 = nalgebra::geometry::Rotation3<f64>;
 type Quaternion = nalgebra::geometry::UnitQuaternion<f64>;
 type Vector3 = nalgebra::base::Vector3<f64>;
+type Vector6 = nalgebra::base::Vector6<f64>;
+type Matrix3 = nalgebra::base::Matrix3<f64>;
+type Matrix4 = nalgebra::base::Matrix4<f64>;
+type Matrix4xX = nalgebra::base::Matrix4xX<f64>;
 type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
 
+// ***************************************************************************
+// Layouts
+// ***************************************************************************
+
+// nalgebra stores matrices column-major, i.e. element (row, col) lives at
+// index row + 4 * col. The two multiplies below do identical arithmetic and
+// differ only in how they index into the flat buffers, so any difference in
+// timing is down to the memory layout alone.
+
+fn multiply_column_major(a: &[f64; 16], b: &[f64; 16]) -> [f64; 16] {
+    let mut c = [0.0; 16];
+    for col in 0..4 {
+        for row in 0..4 {
+            let mut sum = 0.0;
+            for k in 0..4 {
+                sum += a[row + 4 * k] * b[k + 4 * col];
+            }
+            c[row + 4 * col] = sum;
+        }
+    }
+    c
+}
+
+fn multiply_row_major(a: &[f64; 16], b: &[f64; 16]) -> [f64; 16] {
+    let mut c = [0.0; 16];
+    for row in 0..4 {
+        for col in 0..4 {
+            let mut sum = 0.0;
+            for k in 0..4 {
+                sum += a[4 * row + k] * b[4 * k + col];
+            }
+            c[4 * row + col] = sum;
+        }
+    }
+    c
+}
+
+/// An isometry behind a one byte tag with no padding, as it might sit in a
+/// serialised record, knocking it off its natural 8 byte alignment.
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+struct PackedPose {
+    _tag: u8,
+    iso: Isometry3,
+}
+
+// ***************************************************************************
+// Benchmarks
+// ***************************************************************************
+
+// Each representation is timed composing, inverting and transforming a point
+// sub_samples times, see rust_examples::headline for adding one.
+
+/// Repeats `measure` over batches of samples until their total time reaches
+/// `target`, returning the samples completed and the time they took.
+fn measure_for(
+    target: Duration,
+    mut measure: impl FnMut(usize) -> BenchResult,
+) -> (usize, Duration) {
+    // large enough to amortise the clock reads, small enough not to overshoot
+    const BATCH: usize = 10_000;
+    let (mut samples, mut elapsed) = (0, Duration::ZERO);
+    while elapsed < target {
+        elapsed += measure(BATCH).duration;
+        samples += BATCH;
+    }
+    (samples, elapsed)
+}
+
+// ***************************************************************************
+// Verification
+// ***************************************************************************
+
+/// Checks the generated inputs are valid rotations, unit quaternions and
+/// orthonormal, right handed matrices, naming the first one that isn't.
+fn check_rotations(
+    quaternions: &[(&str, Quaternion)],
+    matrices: &[(&str, Matrix3)],
+) -> Result<(), String> {
+    const TOLERANCE: f64 = 1e-12;
+    for (name, q) in quaternions {
+        let norm = q.as_ref().norm();
+        if (norm - 1.0).abs() > TOLERANCE {
+            return Err(format!("{} is not normalised, |q| = {}", name, norm));
+        }
+    }
+    for (name, m) in matrices {
+        if !relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = TOLERANCE)
+            || !relative_eq!(m.determinant(), 1.0, epsilon = TOLERANCE)
+        {
+            return Err(format!("{} is not orthonormal: {}", name, m));
+        }
+    }
+    Ok(())
+}
+
+// ***************************************************************************
+// Arguments
+// ***************************************************************************
+
+/// Every flag, with a one line summary, for --list.
+const FLAGS: &[(&str, &str)] = &[
+    ("--sub-samples <n>", "samples per representation"),
+    (
+        "--sweep",
+        "report time per op across a range of sub_samples",
+    ),
+    (
+        "--duration <s>",
+        "run each representation for a fixed time, report ops per second",
+    ),
+    (
+        "--verify",
+        "check the selected representations agree on every sample first",
+    ),
+    (
+        "--strict",
+        "check the generated rotations are valid before timing",
+    ),
+    ("--unit <unit>", "report times in ns, us, ms, s or auto"),
+    ("--only <names>", "comma separated representations to time"),
+    (
+        "--pin-core <id>",
+        "pin the measuring thread to a core (pin-core feature)",
+    ),
+    ("--list", "list the representations and flags, then exit"),
+    (
+        "--verbose",
+        "print poses in full, log per-sample timings (RUST_LOG=debug)",
+    ),
+    (
+        "--criterion-json <dir>",
+        "also write the headline results as criterion estimates",
+    ),
+];
+
+/// How many samples of each representation --verbose logs one at a time.
+const VERBOSE_SAMPLES: usize = 10;
+
+// ***************************************************************************
+// Logging
+// ***************************************************************************
+
+/// Logs the start and end (with its duration) of one phase of the run, so
+/// long runs can be followed with RUST_LOG=info.
+struct Phase {
+    name: &'static str,
+    start: std::time::SystemTime,
+}
+
+impl Phase {
+    fn start(name: &'static str) -> Self {
+        info!("{} - started", name);
+        Phase {
+            name,
+            start: std::time::SystemTime::now(),
+        }
+    }
+
+    fn end(self) {
+        let duration = self.start.elapsed().unwrap_or_default();
+        info!(
+            "{} - finished in {:.3} seconds",
+            self.name,
+            duration.as_secs_f64()
+        );
+    }
+}
+
+// ***************************************************************************
+// Sections
+// ***************************************************************************
+
+/// What the sections share, the example's poses in each representation and
+/// how to time and report them.
+#[derive(Clone, Copy)]
+struct Inputs {
+    iso1: Isometry3,
+    iso2: Isometry3,
+    iso3: Isometry3,
+    isom1: IsometryMatrix3,
+    isom2: IsometryMatrix3,
+    isom3: IsometryMatrix3,
+    trans1: Transform3,
+    trans2: Transform3,
+    q1: Quaternion,
+    q2: Quaternion,
+    r1: Rotation3,
+    t1: Translation3,
+    t2: Translation3,
+    axisangle1: Vector3,
+    sub_samples: usize,
+    unit: Unit,
+}
+
+/// Layout - Column vs Row Major
+///  - nalgebra is column-major, the row-major buffers are simulated by
+///    storing the transpose and indexing accordingly
+fn layout_column_vs_row_major(inputs: &Inputs) {
+    let Inputs {
+        trans1,
+        trans2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Layout - Column vs Row Major");
+    let column_major1: [f64; 16] = trans1.matrix().as_slice().try_into().unwrap();
+    let column_major2: [f64; 16] = trans2.matrix().as_slice().try_into().unwrap();
+    let row_major1: [f64; 16] = trans1.matrix().transpose().as_slice().try_into().unwrap();
+    let row_major2: [f64; 16] = trans2.matrix().transpose().as_slice().try_into().unwrap();
+    assert_eq!(
+        Matrix4::from_column_slice(&multiply_column_major(&column_major1, &column_major2)),
+        Matrix4::from_row_slice(&multiply_row_major(&row_major1, &row_major2))
+    );
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(multiply_column_major(
+            &black_box(column_major1),
+            &black_box(column_major2),
+        ));
+    });
+    println!("Column-major multiply took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(multiply_row_major(
+            &black_box(row_major1),
+            &black_box(row_major2),
+        ));
+    });
+    println!("Row-major multiply took {}", format_time(duration, unit));
+    phase.end();
+}
+
+/// Inverse - Cache It or Not?
+///  - inverse_transform_point skips building the inverse isometry, but
+///    still redoes the (conjugate) rotation work for every point
+fn inverse_cache_it_or_not(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Inverse - Cache It or Not?");
+    let inverse1 = iso1.inverse();
+    let duration = time(sub_samples, |i| {
+        let p = Point3::new(i as f64, 0.0, 0.0);
+        let _ = black_box(black_box(inverse1) * black_box(p));
+    });
+    println!("Precomputed inverse took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |i| {
+        let p = Point3::new(i as f64, 0.0, 0.0);
+        let _ = black_box(black_box(iso1).inverse_transform_point(&black_box(p)));
+    });
+    println!(
+        "inverse_transform_point took {}",
+        format_time(duration, unit)
+    );
+    phase.end();
+}
+
+/// Conversion - nalgebra::convert vs Manual
+///  - convert goes via the SubsetOf machinery, manual is what the example
+///    above does by hand
+fn conversion_nalgebra_convert_vs_manual(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        trans1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Conversion - nalgebra::convert vs Manual");
+    let converted: Transform3 = nalgebra::convert(iso1);
+    println!(
+        "nalgebra::convert matches from_matrix_unchecked: {}",
+        converted == trans1
+    );
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(nalgebra::convert::<Isometry3, Transform3>(black_box(iso1)));
+    });
+    println!("nalgebra::convert took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(Transform3::from_matrix_unchecked(
+            black_box(iso1).to_homogeneous(),
+        ));
+    });
+    println!("from_matrix_unchecked took {}", format_time(duration, unit));
+    phase.end();
+}
+
+/// Rotation - Quaternion vs Matrix
+///  - the single most common hot operation, rotating a vector, without
+///    the rest of the isometry machinery
+fn rotation_quaternion_vs_matrix(inputs: &Inputs) {
+    let Inputs {
+        q1,
+        r1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Rotation - Quaternion vs Matrix");
+    let duration = time(sub_samples, |i| {
+        let v = Vector3::new(i as f64, 0.0, 0.0);
+        let _ = black_box(black_box(q1) * black_box(v));
+    });
+    println!("Quaternion*vector took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |i| {
+        let v = Vector3::new(i as f64, 0.0, 0.0);
+        let _ = black_box(black_box(q1).to_rotation_matrix() * black_box(v));
+    });
+    println!(
+        "Quaternion->Rotation3*vector took {}",
+        format_time(duration, unit)
+    );
+    let duration = time(sub_samples, |i| {
+        let v = Vector3::new(i as f64, 0.0, 0.0);
+        let _ = black_box(black_box(r1) * black_box(v));
+    });
+    println!("Rotation3*vector took {}", format_time(duration, unit));
+    phase.end();
+}
+
+/// Manifold - Box-Plus / Box-Minus
+///  - the operators on-manifold optimisers iterate with, exp / log under
+///    the hood
+fn manifold_box_plus_box_minus(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Manifold - Box-Plus / Box-Minus");
+    let delta = Vector6::new(0.01, -0.02, 0.03, 0.002, 0.001, -0.003);
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(box_plus(&black_box(iso1), &black_box(delta)));
+    });
+    println!("box_plus took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(box_minus(&black_box(iso1), &black_box(iso2)));
+    });
+    println!("box_minus took {}", format_time(duration, unit));
+    phase.end();
+}
+
+/// Storage - Stack vs Heap
+///  - same composition, operands reached directly, through a Box or by
+///    indexing into a Vec
+fn storage_stack_vs_heap(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Storage - Stack vs Heap");
+    let boxed1 = Box::new(iso1);
+    let boxed2 = Box::new(iso2);
+    let stored = vec![iso1, iso2];
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(black_box(iso1) * black_box(iso2));
+    });
+    println!("Stack composition took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(**black_box(&boxed1) * **black_box(&boxed2));
+    });
+    println!("Boxed composition took {}", format_time(duration, unit));
+    let duration = time(sub_samples, |_| {
+        let stored = black_box(&stored);
+        let _ = black_box(stored[black_box(0)] * stored[black_box(1)]);
+    });
+    println!("Vec composition took {}", format_time(duration, unit));
+    phase.end();
+}
+
+/// Construction - Scaled vs Unit Axis
+///  - Quaternion::new takes a scaled axis (axis * angle), from_axis_angle
+///    takes a Unit<Vector3> and the angle separately, the type system
+///    guarantees the axis was normalised
+fn construction_scaled_vs_unit_axis(inputs: &Inputs) {
+    let Inputs {
+        q1,
+        axisangle1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Construction - Scaled vs Unit Axis");
+    let axis = Vector3::y_axis();
+    let angle = std::f64::consts::FRAC_PI_2;
+    assert!(Quaternion::from_axis_angle(&axis, angle).angle_to(&q1) < 1e-12);
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(Quaternion::new(black_box(axisangle1)));
+    });
+    println!(
+        "Quaternion::new(scaled axis) took {}",
+        format_time(duration, unit)
+    );
+    let duration = time(sub_samples, |_| {
+        let _ = black_box(Quaternion::from_axis_angle(
+            &black_box(axis),
+            black_box(angle),
+        ));
+    });
+    println!(
+        "Quaternion::from_axis_angle(unit axis) took {}",
+        format_time(duration, unit)
+    );
+    phase.end();
+}
+
+/// Interpolation - Sampling a t-Grid
+///  - steady state throughput, e.g. sampling an animation, rather than a
+///    single interpolation
+///  - towards iso3, slerp short-circuits between q1 and q2, the same
+///    rotation
+fn interpolation_sampling_a_t_grid(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso3,
+        isom1,
+        isom3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Interpolation - Sampling a t-Grid");
+    let grid: Vec<f64> = (0..1000).map(|i| i as f64 / 999.0).collect();
+    let lerp_slerp_matrix = |a: &IsometryMatrix3, b: &IsometryMatrix3, t: f64| {
+        IsometryMatrix3::from_parts(
+            Translation3::from(a.translation.vector.lerp(&b.translation.vector, t)),
+            a.rotation.slerp(&b.rotation, t),
+        )
+    };
+    assert!(
+        (iso1.lerp_slerp(&iso3, 0.3).to_homogeneous()
+            - lerp_slerp_matrix(&isom1, &isom3, 0.3).to_homogeneous())
+        .amax()
+            < 1e-12
+    );
+    let repetitions = sub_samples / grid.len();
+    let duration = time(repetitions, |_| {
+        for t in &grid {
+            let _ = black_box(black_box(iso1).lerp_slerp(&black_box(iso3), *t));
+        }
+    });
+    println!(
+        "Isometry lerp_slerp took {} per sample",
+        format_time_per_op(duration, repetitions * grid.len(), unit)
+    );
+    let duration = time(repetitions, |_| {
+        for t in &grid {
+            let _ = black_box(lerp_slerp_matrix(&black_box(isom1), &black_box(isom3), *t));
+        }
+    });
+    println!(
+        "IsometryMatrix lerp/slerp took {} per sample",
+        format_time_per_op(duration, repetitions * grid.len(), unit)
+    );
+    phase.end();
+}
+
+/// Dispatch - Point3 vs Vector3
+///  - points pick up the translation, vectors don't, otherwise it's the
+///    same rotation
+fn dispatch_point3_vs_vector3(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        isom1,
+        trans1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Dispatch - Point3 vs Vector3");
+    let durations = [
+        (
+            "Transform*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(trans1).transform_point(&black_box(p)));
+            }),
+        ),
+        (
+            "Transform*vector",
+            time(sub_samples, |i| {
+                let v = Vector3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(trans1).transform_vector(&black_box(v)));
+            }),
+        ),
+        (
+            "Isometry*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(iso1) * black_box(p));
+            }),
+        ),
+        (
+            "Isometry*vector",
+            time(sub_samples, |i| {
+                let v = Vector3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(iso1) * black_box(v));
+            }),
+        ),
+        (
+            "IsometryMatrix*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(isom1) * black_box(p));
+            }),
+        ),
+        (
+            "IsometryMatrix*vector",
+            time(sub_samples, |i| {
+                let v = Vector3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(isom1) * black_box(v));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!("{} took {}", name, format_time(duration, unit));
+    }
+    phase.end();
+}
+
+/// Orientation - Propagating Through Frames
+///  - only the rotation part of the pose, as when chaining orientation
+///    estimates, also reports how far each has drifted from a valid rotation
+fn orientation_propagating_through_frames(inputs: &Inputs) {
+    let Inputs {
+        iso3,
+        isom3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Orientation - Propagating Through Frames");
+    let mut orientation = Quaternion::identity();
+    let duration = time(sub_samples, |_| {
+        orientation = black_box(black_box(iso3).rotation * orientation);
+    });
+    println!(
+        "Isometry orientation propagation took {} (|q| - 1 = {:e})",
+        format_time(duration, unit),
+        orientation.quaternion().norm() - 1.0
+    );
+    let mut orientation = Rotation3::identity();
+    let duration = time(sub_samples, |_| {
+        orientation = black_box(black_box(isom3).rotation * orientation);
+    });
+    println!(
+        "IsometryMatrix orientation propagation took {} (max |R^T * R - I| = {:e})",
+        format_time(duration, unit),
+        (orientation.matrix().transpose() * orientation.matrix() - nalgebra::Matrix3::identity())
+            .amax()
+    );
+    phase.end();
+}
+
+/// Black Box - Why Bother?
+///  - without it the optimiser sees the result is unused and the inputs
+///    never change, so it is free to hoist or delete the whole loop body
+///  - black boxing only the result stops the deletion, but not the hoisting
+fn black_box_why_bother(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Black Box - Why Bother?");
+    let durations = [
+        (
+            "No black_box",
+            time(sub_samples, |_| {
+                let _ = iso1 * iso2;
+            }),
+        ),
+        (
+            "Result black_box",
+            time(sub_samples, |_| {
+                let _ = black_box(iso1 * iso2);
+            }),
+        ),
+        (
+            "Full black_box",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso1) * black_box(iso2));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!("{} composition took {}", name, format_time(duration, unit));
+    }
+    phase.end();
+}
+
+/// Trajectories - Absolute to Relative
+///  - odometry increments poses[i]^-1 * poses[i + 1] over a whole
+///    trajectory at once, to see if the batch inverse-then-multiply vectorises
+fn trajectories_absolute_to_relative(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Trajectories - Absolute to Relative");
+    let poses: Vec<Isometry3> = (0..1000)
+        .map(|i| {
+            let i = i as f64;
+            Isometry3::new(Vector3::new(0.1 * i, i.sin(), 0.0), Vector3::z() * 0.01 * i)
+        })
+        .collect();
+    let poses_matrix: Vec<IsometryMatrix3> = poses
+        .iter()
+        .map(|pose| {
+            IsometryMatrix3::from_parts(pose.translation, pose.rotation.to_rotation_matrix())
+        })
+        .collect();
+    let poses_transform: Vec<Transform3> = poses
+        .iter()
+        .map(|pose| Transform3::from_matrix_unchecked(pose.to_homogeneous()))
+        .collect();
+    let repetitions = sub_samples / poses.len();
+    let ops = repetitions * (poses.len() - 1);
+    let durations = [
+        (
+            "Transform",
+            time(repetitions, |_| {
+                let relative: Vec<Transform3> = black_box(&poses_transform)
+                    .windows(2)
+                    .filter_map(|pair| pair[0].try_inverse().map(|inverse| inverse * pair[1]))
+                    .collect();
+                let _ = black_box(relative);
+            }),
+        ),
+        (
+            "Isometry",
+            time(repetitions, |_| {
+                let _ = black_box(to_relative(black_box(&poses)));
+            }),
+        ),
+        (
+            "IsometryMatrix",
+            time(repetitions, |_| {
+                let relative: Vec<IsometryMatrix3> = black_box(&poses_matrix)
+                    .windows(2)
+                    .map(|pair| pair[0].inv_mul(&pair[1]))
+                    .collect();
+                let _ = black_box(relative);
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} to_relative took {}, {} per pose",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Caching - Is the Rotation Matrix Worth Storing?
+///  - the same pose applied to a batch of points, Isometry rotates each
+///    with the quaternion, IsometryMatrix already holds the matrix, the
+///    middle row pays for building that matrix once per batch
+fn caching_is_the_rotation_matrix_worth_storing(inputs: &Inputs) {
+    let Inputs {
+        iso3,
+        isom3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Caching - Is the Rotation Matrix Worth Storing?");
+    let points: Vec<Point3> = (0..1000)
+        .map(|i| Point3::new(i as f64, 1.0, -1.0))
+        .collect();
+    let repetitions = sub_samples / points.len();
+    let ops = repetitions * points.len();
+    let durations = [
+        (
+            "Isometry",
+            time(repetitions, |_| {
+                let iso = black_box(iso3);
+                for p in black_box(&points) {
+                    let _ = black_box(iso * p);
+                }
+            }),
+        ),
+        (
+            "Isometry->IsometryMatrix once",
+            time(repetitions, |_| {
+                let iso = black_box(iso3);
+                let isom =
+                    IsometryMatrix3::from_parts(iso.translation, iso.rotation.to_rotation_matrix());
+                for p in black_box(&points) {
+                    let _ = black_box(isom * p);
+                }
+            }),
+        ),
+        (
+            "IsometryMatrix",
+            time(repetitions, |_| {
+                let isom = black_box(isom3);
+                for p in black_box(&points) {
+                    let _ = black_box(isom * p);
+                }
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} batch transform took {}, {} per point",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Comparison - relative_eq! vs ==
+///  - what approximate comparison costs in a tight validation loop,
+///    relative_eq! checks every element against both an absolute and a
+///    relative tolerance
+///  - both compare equal values, the worst case, == would otherwise stop
+///    at the first element that differs
+fn comparison_relative_eq_vs_eq(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Comparison - relative_eq! vs ==");
+    let copy = iso1;
+    assert!(relative_eq!(iso1, copy, epsilon = 1e-12) && iso1 == copy);
+    let (matrix1, matrix_copy) = (iso1.to_homogeneous(), copy.to_homogeneous());
+    let durations = [
+        (
+            "Isometry ==",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso1) == black_box(copy));
+            }),
+        ),
+        (
+            "Isometry relative_eq!",
+            time(sub_samples, |_| {
+                let _ = black_box(relative_eq!(
+                    black_box(iso1),
+                    black_box(copy),
+                    epsilon = 1e-12
+                ));
+            }),
+        ),
+        (
+            "Matrix4 ==",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(matrix1) == black_box(matrix_copy));
+            }),
+        ),
+        (
+            "Matrix4 relative_eq!",
+            time(sub_samples, |_| {
+                let _ = black_box(relative_eq!(
+                    black_box(matrix1),
+                    black_box(matrix_copy),
+                    epsilon = 1e-12
+                ));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per comparison",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Operands - Owned vs Referenced
+///  - nalgebra implements Mul for values and references alike, does
+///    passing references save copying the operands?
+///  - black_box of a value has to spill it to memory every iteration, of
+///    a reference only the pointer, so the by value rows also carry the
+///    copy a reference would have avoided. Once inlined, with the operands
+///    in registers, the two forms usually compile to the same thing.
+fn operands_owned_vs_referenced(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso2,
+        isom1,
+        isom2,
+        trans1,
+        trans2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Operands - Owned vs Referenced");
+    let (sim1, sim2) = (
+        Similarity3::from_isometry(iso1, 1.0),
+        Similarity3::from_isometry(iso2, 1.0),
+    );
+    let durations = [
+        (
+            "Transform a * b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(trans1) * black_box(trans2));
+            }),
+        ),
+        (
+            "Transform &a * &b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&trans1) * black_box(&trans2));
+            }),
+        ),
+        (
+            "Isometry a * b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso1) * black_box(iso2));
+            }),
+        ),
+        (
+            "Isometry &a * &b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&iso1) * black_box(&iso2));
+            }),
+        ),
+        (
+            "IsometryMatrix a * b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(isom1) * black_box(isom2));
+            }),
+        ),
+        (
+            "IsometryMatrix &a * &b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&isom1) * black_box(&isom2));
+            }),
+        ),
+        (
+            "Similarity a * b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(sim1) * black_box(sim2));
+            }),
+        ),
+        (
+            "Similarity &a * &b",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&sim1) * black_box(&sim2));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!("{} took {}", name, format_time(duration, unit));
+    }
+    phase.end();
+}
+
+/// Translation - The Floor
+///  - pure Translation3, no rotation at all, what's left of the isometry
+///    numbers above this is the rotation handling
+fn translation_the_floor(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        iso2,
+        t1,
+        t2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Translation - The Floor");
+    let durations = [
+        (
+            "Translation composition",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(t1) * black_box(t2));
+            }),
+        ),
+        (
+            "Isometry composition",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso1) * black_box(iso2));
+            }),
+        ),
+        (
+            "Translation*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(t1) * black_box(p));
+            }),
+        ),
+        (
+            "Isometry*point",
+            time(sub_samples, |i| {
+                let p = Point3::new(i as f64, 0.0, 0.0);
+                let _ = black_box(black_box(iso1) * black_box(p));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per op",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Views - Look-At
+///  - building a camera's view transform from eye, target and up, the
+///    cross products and normalisations versus nalgebra's own
+fn views_look_at(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Views - Look-At");
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let target = Point3::new(-2.0, 0.5, 1.0);
+    let up = Vector3::z();
+    let durations = [
+        (
+            "look_at",
+            time(sub_samples, |_| {
+                let _ = black_box(look_at(&black_box(eye), &black_box(target), &black_box(up)));
+            }),
+        ),
+        (
+            "Isometry3::look_at_rh",
+            time(sub_samples, |_| {
+                let _ = black_box(Isometry3::look_at_rh(
+                    &black_box(eye),
+                    &black_box(target),
+                    &black_box(up),
+                ));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per view",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Access - Getting the Parts Back Out
+///  - the isometries store rotation and translation as fields, a
+///    Transform has to slice them out of its 4x4 (and convert, if a
+///    quaternion is what's wanted)
+fn access_getting_the_parts_back_out(inputs: &Inputs) {
+    let Inputs {
+        iso1,
+        isom1,
+        trans1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Access - Getting the Parts Back Out");
+    let durations = [
+        (
+            "Isometry .rotation",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&iso1).rotation);
+            }),
+        ),
+        (
+            "Isometry .translation",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&iso1).translation);
+            }),
+        ),
+        (
+            "IsometryMatrix .rotation",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&isom1).rotation);
+            }),
+        ),
+        (
+            "IsometryMatrix .translation",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(&isom1).translation);
+            }),
+        ),
+        (
+            "Transform rotation block",
+            time(sub_samples, |_| {
+                let linear = black_box(&trans1)
+                    .matrix()
+                    .fixed_view::<3, 3>(0, 0)
+                    .into_owned();
+                let rotation = Rotation3::from_matrix_unchecked(linear);
+                let _ = black_box(rotation);
+            }),
+        ),
+        (
+            "Transform rotation as quaternion",
+            time(sub_samples, |_| {
+                let linear = black_box(&trans1)
+                    .matrix()
+                    .fixed_view::<3, 3>(0, 0)
+                    .into_owned();
+                let rotation = Rotation3::from_matrix_unchecked(linear);
+                let _ = black_box(Quaternion::from_rotation_matrix(&rotation));
+            }),
+        ),
+        (
+            "Transform translation column",
+            time(sub_samples, |_| {
+                let column = black_box(&trans1)
+                    .matrix()
+                    .fixed_view::<3, 1>(0, 3)
+                    .into_owned();
+                let translation = Translation3::from(column);
+                let _ = black_box(translation);
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per access",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Storage - Array of Structs vs Struct of Arrays
+///  - composing two batches element-wise, Vec<Isometry3> against the
+///    translations and rotations kept in separate Vecs. Fully split,
+///    per-component arrays are what vectorisers like best, this is the
+///    halfway house that keeps nalgebra's types
+fn storage_array_of_structs_vs_struct_of_arrays(inputs: &Inputs) {
+    let Inputs {
+        iso3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Storage - Array of Structs vs Struct of Arrays");
+    let batch_a: Vec<Isometry3> = (0..1000)
+        .map(|i| {
+            Isometry3::new(
+                Vector3::new(i as f64, 1.0, 2.0),
+                Vector3::new(0.1, 0.2, 1e-3 * i as f64),
+            )
+        })
+        .collect();
+    let batch_b: Vec<Isometry3> = batch_a.iter().map(|iso| iso.inverse() * iso3).collect();
+    let split = |batch: &[Isometry3]| -> (Vec<Vector3>, Vec<Quaternion>) {
+        batch
+            .iter()
+            .map(|iso| (iso.translation.vector, iso.rotation))
+            .unzip()
+    };
+    let (translations_a, rotations_a) = split(&batch_a);
+    let (translations_b, rotations_b) = split(&batch_b);
+    let mut composed = vec![Isometry3::identity(); batch_a.len()];
+    let mut translations = vec![Vector3::zeros(); batch_a.len()];
+    let mut rotations = vec![Quaternion::identity(); batch_a.len()];
+    let repetitions = sub_samples / batch_a.len();
+    let ops = repetitions * batch_a.len();
+    let durations = [
+        (
+            "Array of structs",
+            time(repetitions, |_| {
+                let (a, b) = (black_box(&batch_a), black_box(&batch_b));
+                for ((out, a), b) in composed.iter_mut().zip(a).zip(b) {
+                    *out = a * b;
+                }
+                black_box(&composed);
+            }),
+        ),
+        (
+            "Struct of arrays",
+            time(repetitions, |_| {
+                let (ta, qa) = (black_box(&translations_a), black_box(&rotations_a));
+                let (tb, qb) = (black_box(&translations_b), black_box(&rotations_b));
+                for i in 0..ta.len() {
+                    translations[i] = ta[i] + qa[i] * tb[i];
+                    rotations[i] = qa[i] * qb[i];
+                }
+                black_box(&translations);
+                black_box(&rotations);
+            }),
+        ),
+    ];
+    for (i, (translation, rotation)) in translations.iter().zip(&rotations).enumerate() {
+        assert_eq!(
+            Isometry3::from_parts(Translation3::from(*translation), *rotation),
+            composed[i]
+        );
+    }
+    for (name, duration) in durations {
+        println!(
+            "{} composition took {}, {} per pose",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Rotation - powf vs Repeated Composition
+///  - rotating by the same increment n times, q.powf(n) goes through a
+///    log / exp, so costs the same for any n and rounds once, composing
+///    n times costs n products and the rounding accumulates
+fn rotation_powf_vs_repeated_composition(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Rotation - powf vs Repeated Composition");
+    let increment_axisangle = Vector3::new(0.3, -0.2, 0.9).normalize() * 1e-3;
+    let increment = Quaternion::new(increment_axisangle);
+    let repeated = |n: usize| (0..n).fold(Quaternion::identity(), |q, _| q * increment);
+    for n in [10, 1_000, 100_000] {
+        let exact = Quaternion::new(increment_axisangle * n as f64);
+        println!(
+            "R^{}: powf error {:e} rad, repeated composition error {:e} rad",
+            n,
+            increment.powf(n as f64).angle_to(&exact),
+            repeated(n).angle_to(&exact)
+        );
+    }
+    let n = 100;
+    let repetitions = sub_samples / n;
+    let durations = [
+        (
+            "powf",
+            time(repetitions, |_| {
+                let _ = black_box(black_box(increment).powf(black_box(n) as f64));
+            }),
+        ),
+        (
+            "Repeated composition",
+            time(repetitions, |_| {
+                let increment = black_box(increment);
+                let _ =
+                    black_box((0..black_box(n)).fold(Quaternion::identity(), |q, _| q * increment));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} (n = {}) took {}, {} per R^n",
+            name,
+            n,
+            format_time(duration, unit),
+            format_time_per_op(duration, repetitions, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Construction - Transform3 From Homogeneous vs From Parts
+///  - there's no Transform3::from_parts, the example goes through an
+///    Isometry3 and its to_homogeneous, the alternative is writing the
+///    rotation matrix and translation into the 4x4 by hand
+fn construction_transform3_from_homogeneous_vs_from_parts(inputs: &Inputs) {
+    let Inputs {
+        trans2,
+        q2,
+        t2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Construction - Transform3 From Homogeneous vs From Parts");
+    let from_parts = |t: &Translation3, q: &Quaternion| {
+        let mut m = Matrix4::identity();
+        m.fixed_view_mut::<3, 3>(0, 0)
+            .copy_from(q.to_rotation_matrix().matrix());
+        m.fixed_view_mut::<3, 1>(0, 3).copy_from(&t.vector);
+        Transform3::from_matrix_unchecked(m)
+    };
+    println!(
+        "From parts matches from_matrix_unchecked(iso.to_homogeneous()): {}",
+        relative_eq!(from_parts(&t2, &q2), trans2, epsilon = 1e-15)
+    );
+    let durations = [
+        (
+            "from_matrix_unchecked(Isometry3 .to_homogeneous())",
+            time(sub_samples, |_| {
+                let iso = Isometry3::from_parts(black_box(t2), black_box(q2));
+                let _ = black_box(Transform3::from_matrix_unchecked(iso.to_homogeneous()));
+            }),
+        ),
+        (
+            "from_matrix_unchecked(IsometryMatrix3 .to_homogeneous())",
+            time(sub_samples, |_| {
+                let isom =
+                    IsometryMatrix3::from_parts(black_box(t2), black_box(q2).to_rotation_matrix());
+                let _ = black_box(Transform3::from_matrix_unchecked(isom.to_homogeneous()));
+            }),
+        ),
+        (
+            "Rotation matrix and translation written in place",
+            time(sub_samples, |_| {
+                let _ = black_box(from_parts(&black_box(t2), &black_box(q2)));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per construction",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Keys - Looking Poses Up in a Cache
+///  - f64s aren't Hash / Eq, so a cache keyed by pose either rounds the
+///    pose onto a grid (QuantizedPose) and hashes that, or scans every
+///    entry comparing with a tolerance
+fn keys_looking_poses_up_in_a_cache(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Keys - Looking Poses Up in a Cache");
+    let (translation_step, rotation_step) = (1e-3, 1e-4);
+    let cached: Vec<Isometry3> = (0..1000)
+        .map(|i| {
+            Isometry3::new(
+                Vector3::new(0.1 * i as f64, 1.0, 2.0),
+                Vector3::new(0.1, 0.2, 1e-3 * i as f64),
+            )
+        })
+        .collect();
+    let cache: std::collections::HashMap<QuantizedPose, usize> = cached
+        .iter()
+        .enumerate()
+        .map(|(i, pose)| (QuantizedPose::new(pose, translation_step, rotation_step), i))
+        .collect();
+    let wanted = cached[cached.len() / 2];
+    let scan = |pose: &Isometry3| {
+        cached
+            .iter()
+            .position(|cached| relative_eq!(cached, pose, epsilon = 1e-4))
+    };
+    assert_eq!(
+        cache
+            .get(&QuantizedPose::new(
+                &wanted,
+                translation_step,
+                rotation_step
+            ))
+            .copied(),
+        scan(&wanted)
+    );
+    let durations = [
+        (
+            "QuantizedPose + HashMap::get",
+            time(sub_samples, |_| {
+                let key = QuantizedPose::new(&black_box(wanted), translation_step, rotation_step);
+                let _ = black_box(black_box(&cache).get(&key));
+            }),
+        ),
+        (
+            "Linear scan with relative_eq!",
+            time(sub_samples / cached.len(), |_| {
+                let _ = black_box(scan(&black_box(wanted)));
+            }),
+        ),
+    ];
+    let ops = [sub_samples, sub_samples / cached.len()];
+    for ((name, duration), ops) in durations.into_iter().zip(ops) {
+        println!(
+            "{} ({} entries) took {}, {} per lookup",
+            name,
+            cached.len(),
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Adjoint - Moving Twists Between Frames
+///  - building the 6x6 and multiplying, against the same arithmetic
+///    written out on the 3-vectors, which skips the zero block and the
+///    repeated rotation
+fn adjoint_moving_twists_between_frames(inputs: &Inputs) {
+    let Inputs {
+        iso2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Adjoint - Moving Twists Between Frames");
+    let twist = Vector6::new(0.5, 0.0, 0.1, 0.0, 0.2, 1.0);
+    let by_parts = |iso: &Isometry3, xi: &Vector6| {
+        let v = iso.rotation * xi.fixed_rows::<3>(0).into_owned();
+        let w = iso.rotation * xi.fixed_rows::<3>(3).into_owned();
+        let v = v + iso.translation.vector.cross(&w);
+        Vector6::new(v.x, v.y, v.z, w.x, w.y, w.z)
+    };
+    assert!(relative_eq!(
+        adjoint(&iso2) * twist,
+        by_parts(&iso2, &twist),
+        epsilon = 1e-12
+    ));
+    let durations = [
+        (
+            "adjoint",
+            time(sub_samples, |_| {
+                let _ = black_box(adjoint(&black_box(iso2)));
+            }),
+        ),
+        (
+            "adjoint * twist",
+            time(sub_samples, |_| {
+                let _ = black_box(adjoint(&black_box(iso2)) * black_box(twist));
+            }),
+        ),
+        (
+            "Written out on the parts",
+            time(sub_samples, |_| {
+                let _ = black_box(by_parts(&black_box(iso2), &black_box(twist)));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per call",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Bulk - One Matrix Multiply for a Batch of Points
+///  - the points stacked as the columns of a 4xN homogeneous matrix and
+///    transformed by a single Matrix4 * Matrix4xX, BLAS style, against
+///    iso * p point by point. The multiply also pays for the bottom row
+///    and the w components, which an isometry never needs
+fn bulk_one_matrix_multiply_for_a_batch_of_points(inputs: &Inputs) {
+    let Inputs {
+        iso3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Bulk - One Matrix Multiply for a Batch of Points");
+    let points: Vec<Point3> = (0..1000)
+        .map(|i| Point3::new(i as f64, 1.0, -1.0))
+        .collect();
+    let stacked = Matrix4xX::from_columns(
+        &points
+            .iter()
+            .map(|p| p.to_homogeneous())
+            .collect::<Vec<_>>(),
+    );
+    let homogeneous = iso3.to_homogeneous();
+    let mut moved = vec![Point3::origin(); points.len()];
+    let mut moved_stacked = Matrix4xX::zeros(points.len());
+    homogeneous.mul_to(&stacked, &mut moved_stacked);
+    for (p, column) in points.iter().zip(moved_stacked.column_iter()) {
+        assert!(relative_eq!(
+            iso3 * p,
+            Point3::from_homogeneous(column.into_owned()).unwrap(),
+            epsilon = 1e-12
+        ));
+    }
+    let repetitions = sub_samples / points.len();
+    let ops = repetitions * points.len();
+    let durations = [
+        (
+            "Isometry * p per point",
+            time(repetitions, |_| {
+                let iso = black_box(iso3);
+                for (moved, p) in moved.iter_mut().zip(black_box(&points)) {
+                    *moved = iso * p;
+                }
+                black_box(&moved);
+            }),
+        ),
+        (
+            "Matrix4 * Matrix4xX",
+            time(repetitions, |_| {
+                black_box(homogeneous).mul_to(black_box(&stacked), &mut moved_stacked);
+                black_box(&moved_stacked);
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per point",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Construction - Bulk From Axis-Angles
+///  - the setup cost the headline loop keeps out of its timings, a batch
+///    of scaled axis-angles turned into quaternions or rotation matrices.
+///    Both take a sin / cos of the angle, the matrix then has more entries
+///    to fill in (and store)
+fn construction_bulk_from_axis_angles(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Construction - Bulk From Axis-Angles");
+    let axisangles: Vec<Vector3> = (0..1000)
+        .map(|i| {
+            Vector3::new((0.1 * i as f64).sin(), (0.2 * i as f64).cos(), 0.5) * (1e-3 * i as f64)
+        })
+        .collect();
+    let mut quaternions: Vec<Quaternion> = Vec::with_capacity(axisangles.len());
+    let mut rotations: Vec<Rotation3> = Vec::with_capacity(axisangles.len());
+    let repetitions = sub_samples / axisangles.len();
+    let ops = repetitions * axisangles.len();
+    let durations = [
+        (
+            "Vec<UnitQuaternion>",
+            time(repetitions, |_| {
+                quaternions.clear();
+                quaternions.extend(
+                    black_box(&axisangles)
+                        .iter()
+                        .map(|axisangle| Quaternion::new(*axisangle)),
+                );
+                black_box(&quaternions);
+            }),
+        ),
+        (
+            "Vec<Rotation3>",
+            time(repetitions, |_| {
+                rotations.clear();
+                rotations.extend(
+                    black_box(&axisangles)
+                        .iter()
+                        .map(|axisangle| Rotation3::new(*axisangle)),
+                );
+                black_box(&rotations);
+            }),
+        ),
+    ];
+    for (q, r) in quaternions.iter().zip(&rotations) {
+        assert!(relative_eq!(q.to_rotation_matrix(), *r, epsilon = 1e-12));
+    }
+    for (name, duration) in durations {
+        println!(
+            "{} from axis-angles took {}, {} per element",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Branches - Does the try_inverse Check Cost Anything?
+///  - the Transform path branches on whether the inverse exists. Feed it
+///    batches where it always does, where every other one doesn't (a
+///    pattern the predictor learns) and where a coin toss decides (one it
+///    can't), the singular inputs flatten the z axis
+fn branches_does_the_try_inverse_check_cost_anything(inputs: &Inputs) {
+    let Inputs {
+        trans2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Branches - Does the try_inverse Check Cost Anything?");
+    let mut flattened = trans2.to_homogeneous();
+    flattened.fixed_view_mut::<3, 1>(0, 2).fill(0.0);
+    let singular = Transform3::from_matrix_unchecked(flattened);
+    assert!(singular.try_inverse().is_none() && trans2.try_inverse().is_some());
+    let mut rng = StdRng::seed_from_u64(0);
+    let batches = [
+        ("Always invertible", vec![trans2; 1000]),
+        (
+            "Alternating",
+            (0..1000)
+                .map(|i| if i % 2 == 0 { trans2 } else { singular })
+                .collect(),
+        ),
+        (
+            "Random",
+            (0..1000)
+                .map(|_| if rng.gen_bool(0.5) { trans2 } else { singular })
+                .collect::<Vec<_>>(),
+        ),
+    ];
+    let repetitions = sub_samples / 1000;
+    for (name, batch) in &batches {
+        let mut failed = 0;
+        let duration = time(repetitions, |_| {
+            for transform in black_box(batch) {
+                if let Some(inverse) = transform.try_inverse() {
+                    let _ = black_box(inverse);
+                } else {
+                    failed += 1;
+                }
+            }
+        });
+        println!(
+            "{} took {}, {} per try_inverse ({} of {} failed)",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, repetitions * batch.len(), unit),
+            failed,
+            repetitions * batch.len()
+        );
+    }
+    phase.end();
+}
+
+/// Mixing - Composing Across Representations
+///  - a rigid pose meeting a general transform mid-pipeline, the
+///    isometry is either converted by hand or handed to nalgebra's mixed
+///    operator (which converts it too), against keeping both sides in one
+///    representation
+fn mixing_composing_across_representations(inputs: &Inputs) {
+    let Inputs {
+        iso2,
+        iso3,
+        trans2,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Mixing - Composing Across Representations");
+    let converted = Transform3::from_matrix_unchecked(iso3.to_homogeneous()) * trans2;
+    assert!(relative_eq!(iso3 * trans2, converted, epsilon = 1e-12));
+    let trans3 = Transform3::from_matrix_unchecked(iso3.to_homogeneous());
+    let durations = [
+        (
+            "Isometry * Isometry",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso3) * black_box(iso2));
+            }),
+        ),
+        (
+            "Transform * Transform",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(trans3) * black_box(trans2));
+            }),
+        ),
+        (
+            "Isometry * Transform",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso3) * black_box(trans2));
+            }),
+        ),
+        (
+            "Isometry -> Transform, then *",
+            time(sub_samples, |_| {
+                let iso = Transform3::from_matrix_unchecked(black_box(iso3).to_homogeneous());
+                let _ = black_box(iso * black_box(trans2));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per composition",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Inverse - Rigid vs General Matrix
+///  - Isometry3::inverse conjugates the rotation and rotates the negated
+///    translation, the homogeneous matrix goes through a general 4x4
+///    inverse that knows nothing of the structure. Both land within a
+///    few ulps of the translation's magnitude, the difference is speed,
+///    and that the rigid one can't fail
+fn inverse_rigid_vs_general_matrix(inputs: &Inputs) {
+    let Inputs {
+        iso3,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Inverse - Rigid vs General Matrix");
+    let residual =
+        |m: &Matrix4, iso: &Isometry3| (m * iso.to_homogeneous() - Matrix4::identity()).amax();
+    let (mut worst_rigid, mut worst_general, mut worst_difference) = (0.0, 0.0, 0.0);
+    for i in 0..1000 {
+        let iso = Isometry3::new(
+            Vector3::new(100.0 * i as f64, -3.0, 0.5),
+            Vector3::new(0.3, -1e-3 * i as f64, 1.0),
+        );
+        let general = iso
+            .to_homogeneous()
+            .try_inverse()
+            .expect("rigid transforms are invertible");
+        worst_rigid = f64::max(worst_rigid, residual(&iso.inverse().to_homogeneous(), &iso));
+        worst_general = f64::max(worst_general, residual(&general, &iso));
+        worst_difference = f64::max(
+            worst_difference,
+            (iso.inverse().to_homogeneous() - general).amax(),
+        );
+    }
+    println!(
+        "Worst |inverse * m - I| over 1000 poses: Isometry3::inverse {:e}, Matrix4::try_inverse {:e}",
+        worst_rigid, worst_general
+    );
+    println!(
+        "Worst difference between the two inverses: {:e}",
+        worst_difference
+    );
+    let homogeneous = iso3.to_homogeneous();
+    let durations = [
+        (
+            "Isometry3::inverse",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(iso3).inverse());
+            }),
+        ),
+        (
+            "Matrix4::try_inverse",
+            time(sub_samples, |_| {
+                let _ = black_box(black_box(homogeneous).try_inverse());
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per inverse",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Layout - Aligned vs Packed Storage
+///  - the same batch of poses stored naturally aligned and packed behind
+///    a tag byte. A packed field can't be borrowed, every read copies it
+///    out with unaligned loads, which modern x86 handles near full speed,
+///    other targets (and the SIMD paths some types take) may not
+fn layout_aligned_vs_packed_storage(inputs: &Inputs) {
+    let Inputs {
+        sub_samples, unit, ..
+    } = *inputs;
+    let phase = Phase::start("Layout - Aligned vs Packed Storage");
+    let aligned: Vec<Isometry3> = (0..1000)
+        .map(|i| {
+            Isometry3::new(
+                Vector3::new(i as f64, 1.0, 2.0),
+                Vector3::new(0.1, 0.2, 1e-3 * i as f64),
+            )
+        })
+        .collect();
+    let packed: Vec<PackedPose> = aligned
+        .iter()
+        .map(|iso| PackedPose { _tag: 0, iso: *iso })
+        .collect();
+    println!(
+        "Isometry3 is {} bytes aligned to {}, PackedPose is {} bytes aligned to {}",
+        std::mem::size_of::<Isometry3>(),
+        std::mem::align_of::<Isometry3>(),
+        std::mem::size_of::<PackedPose>(),
+        std::mem::align_of::<PackedPose>()
+    );
+    let point = Point3::new(1.0, -2.0, 3.0);
+    for (iso, pose) in aligned.iter().zip(&packed) {
+        // braces copy the packed field out rather than taking a reference
+        assert_eq!(iso * point, { pose.iso } * point);
+    }
+    let repetitions = sub_samples / aligned.len();
+    let ops = repetitions * aligned.len();
+    let durations = [
+        (
+            "Aligned",
+            time(repetitions, |_| {
+                for iso in black_box(&aligned) {
+                    let _ = black_box(iso * point);
+                }
+            }),
+        ),
+        (
+            "Packed",
+            time(repetitions, |_| {
+                for pose in black_box(&packed) {
+                    let _ = black_box({ pose.iso } * point);
+                }
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} storage took {}, {} per point",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, ops, unit)
+        );
+    }
+    phase.end();
+}
+
+/// Interop - Round Tripping Through mint
+///  - the cost of handing an isometry to another math crate and back,
+///    build with --features mint
+#[cfg(feature = "mint")]
+fn interop_round_tripping_through_mint(inputs: &Inputs) {
+    use rust_examples::transforms::{
+        isometry_from_mint, isometry_from_mint_parts, isometry_to_mint, isometry_to_mint_parts,
+    };
+    let Inputs {
+        iso1,
+        sub_samples,
+        unit,
+        ..
+    } = *inputs;
+    let phase = Phase::start("Interop - Round Tripping Through mint");
+    let durations = [
+        (
+            "mint 4x4 round trip",
+            time(sub_samples, |_| {
+                let _ = black_box(isometry_from_mint(isometry_to_mint(&black_box(iso1))));
+            }),
+        ),
+        (
+            "mint parts round trip",
+            time(sub_samples, |_| {
+                let (translation, rotation) = isometry_to_mint_parts(&black_box(iso1));
+                let _ = black_box(isometry_from_mint_parts(translation, rotation));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            "{} took {}, {} per round trip",
+            name,
+            format_time(duration, unit),
+            format_time_per_op(duration, sub_samples, unit)
+        );
+    }
+    phase.end();
+}
+
 // ***************************************************************************
 // Main
 // ***************************************************************************
 
 fn main() {
-    std::env::set_var("RUST_LOG", "info");
+    let config = Config::from_args(std::env::args()).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        std::process::exit(1);
+    });
+
+    // nothing to measure, skip the banners and setup
+    if config.list {
+        println!("Representations (select with --only)");
+        for registered in REGISTRY {
+            println!(
+                " {:<10} {:<15} {:>4} bytes  {}",
+                registered.key, registered.name, registered.size, registered.description
+            );
+        }
+        println!("Flags");
+        for (flag, summary) in FLAGS {
+            println!(" {:<24} {}", flag, summary);
+        }
+        return;
+    }
+
+    // RUST_LOG=debug adds per-sample detail, default to the phases only
+    if std::env::var_os("RUST_LOG").is_none() {
+        std::env::set_var("RUST_LOG", "info");
+    }
     env_logger::init();
 
+    // Debug builds (debug assertions, next to no inlining) are routinely an
+    // order of magnitude slower and not in a uniform way, don't let anyone
+    // draw conclusions from them
+    if cfg!(debug_assertions) {
+        println!("******************************************************************");
+        println!("* WARNING: debug build, these timings are meaningless.           *");
+        println!("*          Run with --release for numbers worth comparing.       *");
+        println!("******************************************************************");
+    } else {
+        println!("Release build");
+    }
+
+    if let Some(id) = config.pin_core {
+        if pin_to_core(id) {
+            println!("Pinned to core {}", id);
+        } else {
+            println!(
+                "Could not pin to core {} (bad id, unsupported platform or no pin-core feature)",
+                id
+            );
+        }
+    }
+
+    let unit = config.unit;
+
     let axisangle1 = Vector3::y() * std::f64::consts::FRAC_PI_2;
     let q1 = Quaternion::new(axisangle1);
     let r1 = Rotation3::new(axisangle1);
@@ -42,6 +1899,12 @@ fn main() {
     let isom1 = IsometryMatrix3::from_parts(t1, r1);
     let isom2 = IsometryMatrix3::from_parts(t2, r2);
 
+    // q1 and q2 are the same rotation, a third for anything that needs two
+    // different ones, e.g. slerp short-circuits between equal rotations
+    let axisangle3 = Vector3::x() * std::f64::consts::FRAC_PI_3;
+    let iso3 = Isometry3::from_parts(t2, Quaternion::new(axisangle3));
+    let isom3 = IsometryMatrix3::from_parts(t2, Rotation3::new(axisangle3));
+
     // Ugh, returns you a Matrix, not a Transform
     let _this_is_4x4_matrix_not_transform = iso1.to_homogeneous();
 
@@ -49,14 +1912,78 @@ fn main() {
     let trans1 = Transform3::from_matrix_unchecked(iso1.to_homogeneous());
     let trans2 = Transform3::from_matrix_unchecked(iso2.to_homogeneous());
 
+    // from_matrix_unchecked and friends take the inputs on trust, fail fast
+    // rather than benchmark something that isn't a rigid transform
+    if config.strict {
+        let linear = |trans: &Transform3| trans.matrix().fixed_view::<3, 3>(0, 0).into_owned();
+        if let Err(error) = check_rotations(
+            &[
+                ("q1", q1),
+                ("q2", q2),
+                ("iso1.rotation", iso1.rotation),
+                ("iso2.rotation", iso2.rotation),
+            ],
+            &[
+                ("r1", *r1.matrix()),
+                ("r2", *r2.matrix()),
+                ("isom1.rotation", *isom1.rotation.matrix()),
+                ("isom2.rotation", *isom2.rotation.matrix()),
+                ("trans1 linear part", linear(&trans1)),
+                ("trans2 linear part", linear(&trans2)),
+            ],
+        ) {
+            eprintln!("--strict: {}", error);
+            std::process::exit(1);
+        }
+        println!("Strict - all generated rotations are valid");
+    }
+
+    let config = Config {
+        operands: (iso1, iso2),
+        ..config
+    };
+    let representations = config.selected();
+    // a single representation over any number of samples
+    let measure = |key: &'static str, sub_samples: usize| {
+        run(&Config {
+            sub_samples,
+            representations: vec![key],
+            ..config.clone()
+        })
+        .remove(0)
+    };
+
     let p = Point3::new(1.0, 0.0, 0.0);
 
+    // nalgebra's Debug spells out every quaternion component, the compact
+    // form reads at a glance
+    let show = |iso: &Isometry3| {
+        if config.verbose {
+            format!("{} {:?}", format_pose(iso), iso)
+        } else {
+            format_pose(iso)
+        }
+    };
+
     // Usability
     //  - Check for Isometry! Good apis, good operators - no need to convert to homogenous transforms
     //  - Transforms are more awkward ... less operators exist and need 'try' on some apis
 
+    // the example's own poses pitch by pi / 2, gimbal lock, where roll and
+    // yaw share an axis and only yaw - roll comes back, so start with one
+    // that round trips all three
+    println!("Usability - Compact Pose");
+    println!(
+        " - from_pose_euler([1, 2, 3], [0.1, 0.2, 0.3]) {}",
+        show(&from_pose_euler(&[1.0, 2.0, 3.0], &[0.1, 0.2, 0.3]))
+    );
+    println!(
+        " - iso1 {} (pitch pi / 2, gimbal lock, only yaw - roll is meaningful)",
+        show(&iso1)
+    );
+
     println!("Usability - Transform Point");
-    println!(" - iso1*iso2: {:?}", iso1 * iso2);
+    println!(" - iso1*iso2: {}", show(&(iso1 * iso2)));
     println!(" - trans1*trans2: {:?}", trans1 * trans2);
 
     println!("Usability - Transform Point");
@@ -69,52 +1996,252 @@ fn main() {
     println!("Usability - Inverse");
     let identity = iso1 * iso1.inverse();
     assert_eq!(Isometry3::identity(), identity);
-    println!(" - iso1*iso1.inverse() {:?}", identity);
+    println!(" - iso1*iso1.inverse() {}", show(&identity));
     // can't guarantee an inverse with Transform, so...
     if let Some(inverse) = trans1.try_inverse() {
         println!(" - trans1*trans1.inverse() {:?}", trans1 * inverse);
     }
 
-    // Performance - Transform wins here
+    println!("Usability - Rotate About a Pivot");
+    let pivot = Point3::new(1.0, 2.0, 3.0);
+    let about_pivot = rotate_about(&pivot, &q1);
+    println!(" - rotate_about(pivot, q1)*pivot {:?}", about_pivot * pivot);
+
+    println!("Usability - Averaging");
+    println!(" - average(iso1, iso2) {}", show(&average(&[iso1, iso2])));
+    println!(
+        " - weighted_average(1*iso1, 3*iso2) {}",
+        show(&weighted_average(&[(1.0, iso1), (3.0, iso2)]))
+    );
+    println!(" - both pitch pi / 2, gimbal lock, only yaw - roll is meaningful");
+
+    println!("Usability - Checked Conversion");
+    match isometry_try_from_matrix(trans1.matrix()) {
+        Ok(iso) => println!(
+            " - isometry_try_from_matrix(trans1.matrix()) {}",
+            show(&iso)
+        ),
+        Err(error) => println!(" - isometry_try_from_matrix(trans1.matrix()) {}", error),
+    }
+    let mut sheared = trans1.to_homogeneous();
+    sheared[(0, 1)] += 0.1;
+    if let Err(error) = isometry_try_from_matrix(&sheared) {
+        println!(" - isometry_try_from_matrix(sheared) {}", error);
+    }
+    println!(
+        " - nearest_isometry(sheared) {}",
+        show(&nearest_isometry(&Transform3::from_matrix_unchecked(
+            sheared
+        )))
+    );
+
+    // before any of the timing modes, they all run the verified arithmetic
+    if config.verify {
+        let phase = Phase::start("Verification");
+        if let Err(divergence) = verify(&config) {
+            eprintln!("Verification failed - {}", divergence);
+            std::process::exit(1);
+        }
+        println!(
+            "Verified the representations agree over {} samples",
+            config.sub_samples
+        );
+        phase.end();
+    }
+
+    // Performance - in release builds the headline loop ranks IsometryMatrix,
+    // then Isometry, then Transform (tests/ordering.rs, ignored by default as
+    // it's timing sensitive)
     // TODO(stonier) - consider using a better benchmarker here, e.g. criterion
-    {
-        let start = std::time::SystemTime::now();
-        for i in 1..=10000000 {
-            let p = Point3::new(i as f64, 0.0, 0.0);
-            let transform = black_box(black_box(trans1) * black_box(trans2));
-            if let Some(inverse) = transform.try_inverse() {
-                let _ = black_box(black_box(transform) * black_box(inverse));
+    if config.sweep {
+        // Scaling - time per op should be flat, anything else is cache effects
+        let phase = Phase::start("Scaling");
+        println!("Scaling - Time per Op");
+        print!(" {:>11}", "sub_samples");
+        for registered in &representations {
+            print!(" {:>15}", registered.name);
+        }
+        println!();
+        for sub_samples in [1_000, 10_000, 100_000, 1_000_000] {
+            print!(" {:>11}", sub_samples);
+            for registered in &representations {
+                let measurement = measure(registered.key, sub_samples);
+                debug!(
+                    "{} over {} samples: {:?}",
+                    registered.name, sub_samples, measurement
+                );
+                print!(
+                    " {:>15}",
+                    format_time_per_op(measurement.duration, sub_samples, unit)
+                );
             }
-            let _ = black_box(black_box(transform).transform_point(&black_box(p)));
-        }
-        let end = std::time::SystemTime::now();
-        let duration = end.duration_since(start).unwrap();
-        println!("Transform took {} seconds", duration.as_secs_f64());
-    }
-    {
-        let start = std::time::SystemTime::now();
-        for i in 1..=10000000 {
-            let p = Point3::new(i as f64, 0.0, 0.0);
-            let iso = black_box(black_box(iso1) * black_box(iso2));
-            let inverse = iso.inverse();
-            let _ = black_box(black_box(iso) * black_box(inverse));
-            let _ = black_box(black_box(iso) * black_box(p));
-        }
-        let end = std::time::SystemTime::now();
-        let duration = end.duration_since(start).unwrap();
-        println!("Isometry took {} seconds", duration.as_secs_f64());
-    }
-    {
-        let start = std::time::SystemTime::now();
-        for i in 1..=10000000 {
-            let p = Point3::new(i as f64, 0.0, 0.0);
-            let isom = black_box(black_box(isom1) * black_box(isom2));
-            let inverse = isom.inverse();
-            let _ = black_box(black_box(isom) * black_box(inverse));
-            let _ = black_box(black_box(isom) * black_box(p));
-        }
-        let end = std::time::SystemTime::now();
-        let duration = end.duration_since(start).unwrap();
-        println!("IsometryMatrix took {} seconds", duration.as_secs_f64());
+            println!();
+        }
+        phase.end();
+        return;
     }
+
+    if let Some(target) = config.duration {
+        // Throughput - a fixed time rather than a fixed count, so the numbers
+        // are equally stable on fast and slow machines
+        let phase = Phase::start("Throughput");
+        println!(
+            "Throughput - Ops per Second ({} s each)",
+            target.as_secs_f64()
+        );
+        for registered in &representations {
+            let (samples, elapsed) = measure_for(target, |batch| measure(registered.key, batch));
+            debug!(
+                "{} completed {} samples in {:?}",
+                registered.name, samples, elapsed
+            );
+            let rate = match ns_per_op(elapsed, samples) {
+                Some(ns) => format!("{:.3e}", 1e9 / ns),
+                None => "n/a".to_string(),
+            };
+            println!(
+                " - {}: {} ops in {}, {} ops/s",
+                registered.name,
+                samples,
+                format_time(elapsed, unit),
+                rate
+            );
+        }
+        phase.end();
+        return;
+    }
+
+    let sub_samples = config.sub_samples;
+    let phase = Phase::start("Representations");
+    let totals: Vec<(&str, BenchResult)> = run(&config)
+        .into_iter()
+        .map(|result| (result.name, result))
+        .collect();
+    for &(name, measurement) in &totals {
+        debug!("{}: {:?}", name, measurement);
+        println!("{} took {}", name, format_time(measurement.duration, unit));
+        if measurement.failed_inversions > 0 {
+            println!(
+                " - {} of {} inversions failed (skipped)",
+                measurement.failed_inversions, sub_samples
+            );
+        }
+        if !measurement.is_well_formed() {
+            warn!(
+                "{} ended on a degenerate result {:?}, don't trust its timing",
+                name, measurement.last
+            );
+        }
+        if measurement.looks_optimized_away(sub_samples) {
+            println!("******************************************************************");
+            println!(
+                "* WARNING: {:<54}*",
+                format!("{} finished implausibly fast,", name)
+            );
+            println!("*          the compiler may have optimised the loop away.        *");
+            println!("******************************************************************");
+        }
+    }
+    let durations: Vec<Duration> = totals
+        .iter()
+        .map(|(_, measurement)| measurement.duration)
+        .collect();
+    if cfg!(debug_assertions) {
+        println!("Relative to the fastest - withheld, debug build (see the warning above)");
+    } else {
+        println!("Relative to the fastest");
+        for ((name, _), ratio) in totals.iter().zip(ratios_to_fastest(&durations)) {
+            match ratio {
+                Some(1.0) => println!(" - {}: 1.00x (baseline)", name),
+                Some(ratio) => println!(" - {}: {:.2}x", name, ratio),
+                None => println!(" - {}: n/a", name),
+            }
+        }
+    }
+    // per-sample detail, each sample timed on its own after the headline
+    // loop so the logging stays out of its numbers
+    if config.verbose {
+        for registered in &representations {
+            for sample in 1..=VERBOSE_SAMPLES {
+                let measurement = measure(registered.key, 1);
+                debug!(
+                    "{} sample {}: {:?}",
+                    registered.name, sample, measurement.duration
+                );
+            }
+        }
+    }
+    if let Some(dir) = &config.criterion_json {
+        for (name, measurement) in &totals {
+            let Some(ns) = ns_per_op(measurement.duration, sub_samples) else {
+                warn!("{} didn't register on the clock, not exported", name);
+                continue;
+            };
+            if let Err(error) = write_criterion_estimates(dir, "isometry", name, ns) {
+                eprintln!(
+                    "--criterion-json: failed to write {} under {}: {}",
+                    name,
+                    dir.display(),
+                    error
+                );
+                std::process::exit(1);
+            }
+        }
+        println!(
+            "Wrote criterion estimates under {}",
+            dir.join("isometry").display()
+        );
+    }
+    phase.end();
+
+    let inputs = Inputs {
+        iso1,
+        iso2,
+        iso3,
+        isom1,
+        isom2,
+        isom3,
+        trans1,
+        trans2,
+        q1,
+        q2,
+        r1,
+        t1,
+        t2,
+        axisangle1,
+        sub_samples,
+        unit,
+    };
+    layout_column_vs_row_major(&inputs);
+    inverse_cache_it_or_not(&inputs);
+    conversion_nalgebra_convert_vs_manual(&inputs);
+    rotation_quaternion_vs_matrix(&inputs);
+    manifold_box_plus_box_minus(&inputs);
+    storage_stack_vs_heap(&inputs);
+    construction_scaled_vs_unit_axis(&inputs);
+    interpolation_sampling_a_t_grid(&inputs);
+    dispatch_point3_vs_vector3(&inputs);
+    orientation_propagating_through_frames(&inputs);
+    black_box_why_bother(&inputs);
+    trajectories_absolute_to_relative(&inputs);
+    caching_is_the_rotation_matrix_worth_storing(&inputs);
+    comparison_relative_eq_vs_eq(&inputs);
+    operands_owned_vs_referenced(&inputs);
+    translation_the_floor(&inputs);
+    views_look_at(&inputs);
+    access_getting_the_parts_back_out(&inputs);
+    storage_array_of_structs_vs_struct_of_arrays(&inputs);
+    rotation_powf_vs_repeated_composition(&inputs);
+    construction_transform3_from_homogeneous_vs_from_parts(&inputs);
+    keys_looking_poses_up_in_a_cache(&inputs);
+    adjoint_moving_twists_between_frames(&inputs);
+    bulk_one_matrix_multiply_for_a_batch_of_points(&inputs);
+    construction_bulk_from_axis_angles(&inputs);
+    branches_does_the_try_inverse_check_cost_anything(&inputs);
+    mixing_composing_across_representations(&inputs);
+    inverse_rigid_vs_general_matrix(&inputs);
+    layout_aligned_vs_packed_storage(&inputs);
+
+    #[cfg(feature = "mint")]
+    interop_round_tripping_through_mint(&inputs);
 }
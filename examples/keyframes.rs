@@ -0,0 +1,161 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Keyframes - Sampling a sparse animation at frame rate
+//
+// Q: What does a frame cost?
+// A: Finding the two keyframes either side of the frame's time, then a
+//    lerp_slerp between them. Trajectory::sample binary searches for the
+//    pair every time.
+//
+// Q: Can the search be skipped?
+// A: Playback moves forward a frame at a time, so the bracketing pair is
+//    almost always the one used last frame, or the next. Cache its index
+//    and walk forward from there, the search becomes a comparison or two.
+//
+// Q: Does it matter?
+// A: The search grows (logarithmically) with the keyframe count, the
+//    lerp_slerp doesn't. Measured below for a few clip densities.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::{format_ns_per_op, time};
+use rust_examples::transforms::Trajectory;
+
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Plays a trajectory forward, remembering the keyframe pair it last
+/// sampled between.
+struct Player<'a> {
+    keyframes: &'a [(f64, Isometry3)],
+    /// Index of the keyframe at or before the last sampled time.
+    current: usize,
+}
+
+impl<'a> Player<'a> {
+    fn new(trajectory: &'a Trajectory) -> Self {
+        Player {
+            keyframes: trajectory.poses(),
+            current: 0,
+        }
+    }
+
+    /// As [`Trajectory::sample`], cheapest when t doesn't go backwards.
+    fn sample(&mut self, t: f64) -> Option<Isometry3> {
+        if !t.is_finite() {
+            return None;
+        }
+        let (first, last) = (self.keyframes.first()?, self.keyframes.last()?);
+        if t <= first.0 {
+            self.current = 0;
+            return Some(first.1);
+        }
+        if t >= last.0 {
+            return Some(last.1);
+        }
+        if self.keyframes[self.current].0 > t {
+            // rewound, start over
+            self.current = 0;
+        }
+        while self.keyframes[self.current + 1].0 <= t {
+            self.current += 1;
+        }
+        let ((t0, pose0), (t1, pose1)) = (
+            self.keyframes[self.current],
+            self.keyframes[self.current + 1],
+        );
+        Some(pose0.lerp_slerp(&pose1, (t - t0) / (t1 - t0)))
+    }
+}
+
+/// A clip of `count` keyframes spread evenly over `duration` seconds,
+/// wandering and turning.
+fn clip(count: usize, duration: f64) -> Trajectory {
+    let mut trajectory = Trajectory::new();
+    for i in 0..count {
+        let t = duration * i as f64 / (count - 1) as f64;
+        trajectory.push(
+            t,
+            Isometry3::new(
+                Vector3::new(t.sin(), t.cos(), 0.1 * t),
+                Vector3::new(0.3 * (2.0 * t).sin(), 0.2 * t.cos(), 0.5 * t),
+            ),
+        );
+    }
+    trajectory
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // 60fps over 10s
+    let (fps, duration) = (60.0, 10.0);
+    let frames: Vec<f64> = (0..=(fps * duration) as usize)
+        .map(|frame| frame as f64 / fps)
+        .collect();
+
+    println!("Keyframes - Agreement");
+    let trajectory = clip(11, duration);
+    let mut player = Player::new(&trajectory);
+    for t in &frames {
+        assert_eq!(player.sample(*t), trajectory.sample(*t));
+    }
+    // and after a rewind
+    assert_eq!(player.sample(2.5), trajectory.sample(2.5));
+    println!(
+        " - cached and searched samples agree over {} frames",
+        frames.len()
+    );
+
+    println!("Keyframes - Per Frame Cost ({} frames)", frames.len());
+    let repetitions = 2000;
+    let ops = repetitions * frames.len();
+    for count in [11, 101, 1001, 10001] {
+        let trajectory = clip(count, duration);
+        let durations = [
+            (
+                "searched",
+                time(repetitions, |_| {
+                    for t in black_box(&frames) {
+                        let _ = black_box(trajectory.sample(*t));
+                    }
+                }),
+            ),
+            (
+                "cached",
+                time(repetitions, |_| {
+                    let mut player = Player::new(black_box(&trajectory));
+                    for t in black_box(&frames) {
+                        let _ = black_box(player.sample(*t));
+                    }
+                }),
+            ),
+        ];
+        for (name, duration) in durations {
+            println!(
+                " - {} keyframes, {} took {} seconds, {} ns per frame",
+                count,
+                name,
+                duration.as_secs_f64(),
+                format_ns_per_op(duration, ops)
+            );
+        }
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,158 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Mesh - Moving a whole mesh, vertices and normals
+//
+// Q: Points and normals both, how do they differ?
+// A: Vertices are points, they pick up the translation. Normals are
+//    directions, they only rotate, i.e. transform them as vectors.
+//
+// Q: Is that always right for normals?
+// A: For rigid motions, yes. Under a general affine map normals need the
+//    inverse transpose of the linear part (and renormalising), a non-uniform
+//    scale would otherwise tilt them off the surface.
+//
+// The mesh is a generated UV sphere, large enough (~1M vertices) to be
+// limited by memory bandwidth rather than the arithmetic.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::{format_ns_per_op, ns_per_op, time};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Vertices and per-vertex normals, index buffers omitted as they don't move.
+struct Mesh {
+    vertices: Vec<Point3>,
+    normals: Vec<Vector3>,
+}
+
+impl Mesh {
+    /// A UV sphere of the given radius, rings x segments vertices.
+    fn sphere(radius: f64, rings: usize, segments: usize) -> Self {
+        let normals: Vec<Vector3> = (0..rings)
+            .flat_map(|ring| {
+                let polar = std::f64::consts::PI * (ring as f64 + 0.5) / rings as f64;
+                (0..segments).map(move |segment| {
+                    let azimuth = std::f64::consts::TAU * segment as f64 / segments as f64;
+                    Vector3::new(
+                        polar.sin() * azimuth.cos(),
+                        polar.sin() * azimuth.sin(),
+                        polar.cos(),
+                    )
+                })
+            })
+            .collect();
+        let vertices = normals
+            .iter()
+            .map(|normal| Point3::from(normal * radius))
+            .collect();
+        Mesh { vertices, normals }
+    }
+
+    /// Moves the mesh into a preallocated one, vertices as points, normals as
+    /// vectors.
+    fn transform_into(&self, iso: &Isometry3, out: &mut Mesh) {
+        for (moved, vertex) in out.vertices.iter_mut().zip(&self.vertices) {
+            *moved = iso * vertex;
+        }
+        for (moved, normal) in out.normals.iter_mut().zip(&self.normals) {
+            *moved = iso * normal;
+        }
+    }
+
+    /// As [`Mesh::transform_into`], with the rotation converted to a matrix
+    /// once up front.
+    fn transform_into_matrix(&self, iso: &IsometryMatrix3, out: &mut Mesh) {
+        for (moved, vertex) in out.vertices.iter_mut().zip(&self.vertices) {
+            *moved = iso * vertex;
+        }
+        for (moved, normal) in out.normals.iter_mut().zip(&self.normals) {
+            *moved = iso * normal;
+        }
+    }
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    let mesh = Mesh::sphere(2.0, 1000, 1000);
+    let mut moved = Mesh::sphere(2.0, 1000, 1000);
+    let iso = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, 0.2, -0.5));
+    let isom = IsometryMatrix3::from_parts(iso.translation, iso.rotation.to_rotation_matrix());
+
+    println!("Mesh - Correctness ({} vertices)", mesh.vertices.len());
+    mesh.transform_into(&iso, &mut moved);
+    let worst_normal = moved
+        .normals
+        .iter()
+        .map(|n| (n.norm() - 1.0).abs())
+        .fold(0.0, f64::max);
+    println!(" - worst | |n| - 1 |: {:e}", worst_normal);
+    assert!(worst_normal < 1e-12);
+    // each normal still points out of the sphere, away from its moved centre
+    let centre = iso * Point3::origin();
+    let worst_direction = moved
+        .vertices
+        .iter()
+        .zip(&moved.normals)
+        .map(|(vertex, normal)| ((vertex - centre).normalize() - normal).norm())
+        .fold(0.0, f64::max);
+    println!(
+        " - worst normal deviation from the surface normal: {:e}",
+        worst_direction
+    );
+    assert!(worst_direction < 1e-12);
+
+    println!("Mesh - Throughput");
+    let repetitions = 20;
+    let vertices = repetitions * mesh.vertices.len();
+    let durations = [
+        (
+            "Isometry",
+            time(repetitions, |_| {
+                mesh.transform_into(black_box(&iso), &mut moved);
+                black_box(&moved);
+            }),
+        ),
+        (
+            "IsometryMatrix",
+            time(repetitions, |_| {
+                mesh.transform_into_matrix(black_box(&isom), &mut moved);
+                black_box(&moved);
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        let rate = match ns_per_op(duration, vertices) {
+            Some(ns) => format!("{:.1}M", 1e3 / ns),
+            None => "n/a".to_string(),
+        };
+        println!(
+            " - {} took {} seconds, {} ns per vertex (+ normal), {} vertices/s",
+            name,
+            duration.as_secs_f64(),
+            format_ns_per_op(duration, vertices),
+            rate
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,70 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Precision - What happens far from the origin?
+//
+// Q: Does transforming points get less accurate far from the origin?
+// A: Yes, an f64 has ~16 significant digits, so a frame 1e7m out (roughly
+//    earth-centred coordinates) only has ~1e-9m left for the local detail.
+//
+// Q: Does it get slower?
+// A: No, floating point ops cost the same regardless of magnitude (barring
+//    denormals, which large numbers don't produce).
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use rust_examples::bench::time;
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Round trips a local point out to the world frame and back again,
+/// returning how far it drifted.
+fn round_trip_error(world_t_local: &Isometry3, local_point: &Point3) -> f64 {
+    let world_point = world_t_local * local_point;
+    let recovered = world_t_local.inverse_transform_point(&world_point);
+    (recovered - local_point).norm()
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    let sub_samples = 10000000;
+    let local_point = Point3::new(0.123456789, -0.987654321, 0.5);
+    let axisangle = Vector3::new(0.1, 0.2, 0.3);
+
+    println!("Precision - Round Trip Error vs Distance From Origin");
+    for offset in [0.0, 1e3, 1e5, 1e7, 1e9] {
+        let world_t_local = Isometry3::new(Vector3::new(offset, offset, offset), axisangle);
+        let error = round_trip_error(&world_t_local, &local_point);
+
+        let duration = time(sub_samples, |i| {
+            let p = Point3::new(i as f64 * 1e-9, 0.0, 0.0);
+            let _ = black_box(black_box(world_t_local) * black_box(p));
+        });
+
+        println!(
+            " - offset {:e}m: error {:e}m, took {} seconds",
+            offset,
+            error,
+            duration.as_secs_f64()
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
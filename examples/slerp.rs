@@ -0,0 +1,149 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Slerp - Interpolating between nearly antipodal quaternions
+//
+// Q: What's special about antipodal quaternions?
+// A: q and -q are the same rotation. Two rotations that are close together
+//    can be stored as quaternions that are nearly opposite (dot product near
+//    -1), e.g. after a sign flip in some upstream conversion.
+//
+// Q: What goes wrong?
+// A: Slerp straight from the textbook interpolates along the great circle
+//    between the two 4-vectors, the long way round, a near full turn instead
+//    of a nudge. At exactly antipodal it also divides by sin(pi) ~ 1e-16.
+//
+// Q: The fix?
+// A: Negate one of them when the dot product is negative, which picks the
+//    shortest arc. That turns antipodal into coincident, where sin(theta) ~ 0
+//    is just as bad, so also fall back to a normalised lerp for tiny angles.
+//    nalgebra's slerp already does both.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use approx::assert_relative_eq;
+use rust_examples::bench::{format_ns_per_op, time};
+
+type Quaternion = nalgebra::geometry::UnitQuaternion<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Textbook slerp on the raw 4-vectors, no sign check.
+fn naive_slerp(a: &Quaternion, b: &Quaternion, t: f64) -> Quaternion {
+    let (a, b) = (a.coords, b.coords);
+    let theta = a.dot(&b).clamp(-1.0, 1.0).acos();
+    let coords = (a * ((1.0 - t) * theta).sin() + b * (t * theta).sin()) / theta.sin();
+    Quaternion::new_normalize(nalgebra::Quaternion::from(coords))
+}
+
+/// The naive slerp, after negating `b` if it lies in the other hemisphere,
+/// and a normalised lerp when the two are too close for sin(theta) to divide
+/// by.
+fn shortest_slerp(a: &Quaternion, b: &Quaternion, t: f64) -> Quaternion {
+    let b = if a.coords.dot(&b.coords) < 0.0 {
+        Quaternion::new_unchecked(-b.into_inner())
+    } else {
+        *b
+    };
+    if a.coords.dot(&b.coords) > 1.0 - 1e-12 {
+        Quaternion::new_normalize(nalgebra::Quaternion::from(a.coords.lerp(&b.coords, t)))
+    } else {
+        naive_slerp(a, &b, t)
+    }
+}
+
+/// Total rotation swept out sampling the interpolation at 100 steps,
+/// NaN if any sample was.
+fn path_length(interpolate: impl Fn(f64) -> Quaternion) -> f64 {
+    (0..100)
+        .map(|i| interpolate(i as f64 / 100.0).angle_to(&interpolate((i + 1) as f64 / 100.0)))
+        .sum()
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // Two rotations 0.01 rad apart, the second stored with its sign flipped
+    let a = Quaternion::new(Vector3::new(0.3, -0.2, 0.5));
+    let nearby = Quaternion::new(Vector3::z() * 0.01) * a;
+    let b = Quaternion::new_unchecked(-nearby.into_inner());
+    println!(
+        "Slerp - Nearly Antipodal (dot = {:.6})",
+        a.coords.dot(&b.coords)
+    );
+    assert_relative_eq!(a.angle_to(&b), 0.01, epsilon = 1e-12);
+
+    let naive = path_length(|t| naive_slerp(&a, &b, t));
+    let shortest = path_length(|t| shortest_slerp(&a, &b, t));
+    let library = path_length(|t| a.slerp(&b, t));
+    println!(" - naive slerp path length: {:.6} rad", naive);
+    println!(" - shortest arc slerp path length: {:.6} rad", shortest);
+    println!(" - nalgebra slerp path length: {:.6} rad", library);
+    assert_relative_eq!(shortest, 0.01, epsilon = 1e-9);
+    assert_relative_eq!(library, 0.01, epsilon = 1e-9);
+    assert!(naive > 6.0);
+
+    println!("Slerp - Exactly Antipodal (dot = -1)");
+    let b = Quaternion::new_unchecked(-a.into_inner());
+    println!(
+        " - naive slerp halfway: {:?}",
+        naive_slerp(&a, &b, 0.5).coords
+    );
+    println!(
+        " - shortest arc slerp halfway: {:?}",
+        shortest_slerp(&a, &b, 0.5).coords
+    );
+    println!(" - nalgebra slerp halfway: {:?}", a.slerp(&b, 0.5).coords);
+    assert!(shortest_slerp(&a, &b, 0.5).angle_to(&a) < 1e-12);
+    assert!(a.slerp(&b, 0.5).angle_to(&a) < 1e-12);
+
+    println!("Slerp - Timing");
+    let b = Quaternion::new_unchecked(-nearby.into_inner());
+    let sub_samples = 10000000;
+    let durations = [
+        (
+            "naive",
+            time(sub_samples, |i| {
+                let t = (i % 1000) as f64 / 999.0;
+                let _ = black_box(naive_slerp(&black_box(a), &black_box(b), black_box(t)));
+            }),
+        ),
+        (
+            "shortest arc",
+            time(sub_samples, |i| {
+                let t = (i % 1000) as f64 / 999.0;
+                let _ = black_box(shortest_slerp(&black_box(a), &black_box(b), black_box(t)));
+            }),
+        ),
+        (
+            "nalgebra",
+            time(sub_samples, |i| {
+                let t = (i % 1000) as f64 / 999.0;
+                let _ = black_box(black_box(a).slerp(&black_box(b), black_box(t)));
+            }),
+        ),
+    ];
+    for (name, duration) in durations {
+        println!(
+            " - {} slerp took {} seconds, {} ns per slerp",
+            name,
+            duration.as_secs_f64(),
+            format_ns_per_op(duration, sub_samples)
+        );
+    }
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
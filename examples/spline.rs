@@ -0,0 +1,137 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Splines - Smooth trajectories through a set of poses
+//
+// Q: Why not just lerp_slerp between consecutive poses?
+// A: The velocity jumps at every control pose, the path has corners.
+//
+// Q: What does Catmull-Rom buy you?
+// A: The path still passes through every control pose, but the tangent at
+//    each is (p[i+1] - p[i-1]) / 2, shared by the segments either side, so the
+//    translation is C1 continuous.
+//
+// Q: And the rotation?
+// A: Slerped per segment here. Smoothing that too needs SQUAD or a cumulative
+//    B-spline on SO(3), a larger story.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+
+use approx::assert_relative_eq;
+use rust_examples::bench::{format_ns_per_op, time};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Translation3 = nalgebra::geometry::Translation3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Uniform Catmull-Rom spline through a sequence of control poses.
+pub struct CatmullRom {
+    poses: Vec<Isometry3>,
+}
+
+impl CatmullRom {
+    /// Needs at least two control poses.
+    pub fn new(poses: Vec<Isometry3>) -> Self {
+        assert!(
+            poses.len() >= 2,
+            "a spline needs at least two control poses"
+        );
+        Self { poses }
+    }
+
+    /// Evaluates the spline at s in [0, n - 1], passing through control
+    /// pose i at s = i. Out of range values are clamped.
+    pub fn evaluate(&self, s: f64) -> Isometry3 {
+        let last = self.poses.len() - 1;
+        let s = s.clamp(0.0, last as f64);
+        let i = (s.floor() as usize).min(last - 1);
+        let u = s - i as f64;
+
+        // the end segments borrow their missing neighbour from the end itself
+        let p0 = self.poses[i.saturating_sub(1)].translation.vector;
+        let p1 = self.poses[i].translation.vector;
+        let p2 = self.poses[i + 1].translation.vector;
+        let p3 = self.poses[(i + 2).min(last)].translation.vector;
+        let translation = 0.5
+            * (2.0 * p1
+                + (p2 - p0) * u
+                + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u * u
+                + (3.0 * p1 - p0 - 3.0 * p2 + p3) * u * u * u);
+
+        let rotation = self.poses[i].rotation.slerp(&self.poses[i + 1].rotation, u);
+        Isometry3::from_parts(Translation3::from(translation), rotation)
+    }
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    let spline = CatmullRom::new(vec![
+        Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::zeros()),
+        Isometry3::new(Vector3::new(1.0, 1.0, 0.0), Vector3::z() * 0.5),
+        Isometry3::new(Vector3::new(2.0, 0.0, 0.5), Vector3::z() * 1.0),
+        Isometry3::new(Vector3::new(3.0, -1.0, 0.5), Vector3::new(0.2, 0.0, 1.5)),
+        Isometry3::new(Vector3::new(4.0, 0.0, 0.0), Vector3::z() * 2.0),
+    ]);
+
+    println!("Spline - Passes Through the Control Poses");
+    for (i, pose) in spline.poses.iter().enumerate() {
+        assert_relative_eq!(spline.evaluate(i as f64), *pose, epsilon = 1e-12);
+    }
+    let smooth = |s: f64| Point3::from(spline.evaluate(s).translation.vector);
+    for s in [0.0, 0.5, 1.0, 1.5, 2.0] {
+        println!(" - s = {}: {:?}", s, smooth(s));
+    }
+
+    println!("Spline - No Corners");
+    // lerp_slerp's velocity jumps at a control pose, the spline's doesn't
+    let lerp = |s: f64| {
+        let i = (s.floor() as usize).min(3);
+        let pose = spline.poses[i].lerp_slerp(&spline.poses[i + 1], s - i as f64);
+        Point3::from(pose.translation.vector)
+    };
+    let velocity_jump_at_one = |f: &dyn Fn(f64) -> Point3| {
+        let h = 1e-6;
+        let before = (f(1.0 - h) - f(1.0 - 2.0 * h)) / h;
+        let after = (f(1.0 + 2.0 * h) - f(1.0 + h)) / h;
+        (after - before).norm()
+    };
+    println!(
+        " - lerp_slerp velocity jump at s = 1: {:.3}",
+        velocity_jump_at_one(&lerp)
+    );
+    println!(
+        " - spline velocity jump at s = 1: {:.3}",
+        velocity_jump_at_one(&smooth)
+    );
+
+    println!("Spline - Evaluation");
+    let sub_samples = 10000000;
+    let end = (spline.poses.len() - 1) as f64;
+    let duration = time(sub_samples, |i| {
+        let s = end * (i % 1000) as f64 / 999.0;
+        let _ = black_box(black_box(&spline).evaluate(black_box(s)));
+    });
+    println!(
+        " - took {} seconds, {} ns per evaluation",
+        duration.as_secs_f64(),
+        format_ns_per_op(duration, sub_samples)
+    );
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,176 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Trajectory Error - ATE and RPE, the standard SLAM evaluation metrics
+//
+// Q: What's the difference?
+// A: Absolute Trajectory Error compares each estimated pose with the ground
+//    truth directly, so measures global consistency, early drift shows up in
+//    every later pose. Relative Pose Error compares the motion between
+//    consecutive poses, so measures local accuracy (drift per step).
+//
+// Q: Why align first?
+// A: The estimate usually lives in its own frame (e.g. wherever odometry
+//    started), ATE is meaningless until the two trajectories share one.
+//    Anchoring the first poses together is the simplest way, but hands all
+//    of the first pose's error to every other pose. A least squares alignment
+//    of the positions over the whole trajectory (Umeyama) is kinder.
+//
+// The trajectories are synthetic, a ground truth and an estimate with noise
+// and a slow yaw drift.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_examples::transforms::{align, pose_distance, to_relative};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+// ***************************************************************************
+// Definitions
+// ***************************************************************************
+
+/// Root mean square of a set of errors.
+fn rmse(errors: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = errors.fold((0.0, 0), |(sum, count), error| {
+        (sum + error * error, count + 1)
+    });
+    if count == 0 {
+        0.0
+    } else {
+        (sum / count as f64).sqrt()
+    }
+}
+
+/// Re-expresses the estimate so its first pose coincides with the ground
+/// truth's first pose.
+fn align_first_pose(ground_truth: &[Isometry3], estimate: &[Isometry3]) -> Vec<Isometry3> {
+    let gt_t_est = ground_truth[0] * estimate[0].inverse();
+    estimate.iter().map(|pose| gt_t_est * pose).collect()
+}
+
+/// Re-expresses the estimate in the frame that best fits its positions onto
+/// the ground truth's.
+fn align_least_squares(ground_truth: &[Isometry3], estimate: &[Isometry3]) -> Vec<Isometry3> {
+    let positions = |poses: &[Isometry3]| -> Vec<Point3> {
+        poses
+            .iter()
+            .map(|pose| Point3::from(pose.translation.vector))
+            .collect()
+    };
+    let gt_t_est = align(&positions(estimate), &positions(ground_truth));
+    estimate.iter().map(|pose| gt_t_est * pose).collect()
+}
+
+/// Absolute Trajectory Error, RMSE of the translation differences.
+fn ate(ground_truth: &[Isometry3], estimate: &[Isometry3]) -> f64 {
+    rmse(
+        ground_truth
+            .iter()
+            .zip(estimate)
+            .map(|(gt, est)| (gt.translation.vector - est.translation.vector).norm()),
+    )
+}
+
+/// Relative Pose Error between consecutive poses, RMSE of the translation
+/// (metres) and rotation (radians) parts of the per-step error.
+fn rpe(ground_truth: &[Isometry3], estimate: &[Isometry3]) -> (f64, f64) {
+    let errors: Vec<Isometry3> = to_relative(ground_truth)
+        .iter()
+        .zip(to_relative(estimate))
+        .map(|(gt, est)| gt.inv_mul(&est))
+        .collect();
+    (
+        rmse(errors.iter().map(|error| error.translation.vector.norm())),
+        rmse(errors.iter().map(|error| error.rotation.angle())),
+    )
+}
+
+// ***************************************************************************
+// Main
+// ***************************************************************************
+
+fn main() {
+    std::env::set_var("RUST_LOG", "info");
+    env_logger::init();
+
+    // A lap of a circle, the estimate starts off in its own frame
+    let mut rng = StdRng::seed_from_u64(0);
+    let ground_truth: Vec<Isometry3> = (0..200)
+        .map(|i| {
+            let angle = i as f64 * std::f64::consts::TAU / 200.0;
+            Isometry3::new(
+                Vector3::new(10.0 * angle.cos(), 10.0 * angle.sin(), 0.0),
+                Vector3::z() * (angle + std::f64::consts::FRAC_PI_2),
+            )
+        })
+        .collect();
+    let origin = Isometry3::new(Vector3::new(-3.0, 1.0, 0.5), Vector3::z() * 0.3);
+    let estimate: Vec<Isometry3> = ground_truth
+        .iter()
+        .enumerate()
+        .map(|(i, pose)| {
+            let drift = Isometry3::rotation(Vector3::z() * 1e-3 * i as f64);
+            let noise = Isometry3::new(
+                Vector3::from_fn(|_, _| rng.gen_range(-0.05..0.05)),
+                Vector3::from_fn(|_, _| rng.gen_range(-0.005..0.005)),
+            );
+            origin * drift * pose * noise
+        })
+        .collect();
+
+    println!("Trajectory Error - Unaligned");
+    println!(" - ATE: {:.3}m", ate(&ground_truth, &estimate));
+    let (translation, rotation) = rpe(&ground_truth, &estimate);
+    println!(
+        " - RPE: {:.3}m, {:.3}deg",
+        translation,
+        rotation.to_degrees()
+    );
+
+    println!("Trajectory Error - First Pose Aligned");
+    let aligned = align_first_pose(&ground_truth, &estimate);
+    println!(" - ATE: {:.3}m", ate(&ground_truth, &aligned));
+    // relative motions don't care which frame the trajectory is in
+    let (translation, rotation) = rpe(&ground_truth, &aligned);
+    println!(
+        " - RPE: {:.3}m, {:.3}deg",
+        translation,
+        rotation.to_degrees()
+    );
+    println!(
+        " - pose_distance RMSE: {:.3}",
+        rmse(
+            ground_truth
+                .iter()
+                .zip(&aligned)
+                .map(|(gt, est)| pose_distance(gt, est))
+        )
+    );
+
+    println!("Trajectory Error - Least Squares Aligned");
+    let aligned = align_least_squares(&ground_truth, &estimate);
+    println!(" - ATE: {:.3}m", ate(&ground_truth, &aligned));
+    let (translation, rotation) = rpe(&ground_truth, &aligned);
+    println!(
+        " - RPE: {:.3}m, {:.3}deg",
+        translation,
+        rotation.to_degrees()
+    );
+    println!(
+        " - pose_distance RMSE: {:.3}",
+        rmse(
+            ground_truth
+                .iter()
+                .zip(&aligned)
+                .map(|(gt, est)| pose_distance(gt, est))
+        )
+    );
+
+    println!("\nMay you be blessed by a tickle from his noodly appendages...\n");
+}
@@ -0,0 +1,198 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Measurement and reporting helpers for the benchmark examples.
+//
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+// ***************************************************************************
+// Measuring
+// ***************************************************************************
+
+/// Times `op` over `samples` iterations, handing it the (1-based) iteration
+/// so it can vary its inputs.
+pub fn time<F: FnMut(usize)>(samples: usize, mut op: F) -> Duration {
+    // monotonic, unlike SystemTime a clock adjustment can't run it backwards
+    let start = Instant::now();
+    for i in 1..=samples {
+        op(i);
+    }
+    start.elapsed()
+}
+
+/// Pins the current thread to the given core so that the measurements aren't
+/// polluted by the scheduler migrating it mid-run.
+///
+/// Returns whether the thread was pinned, false for an id that isn't one of
+/// the machine's cores. Always a no-op (false) without the `pin-core`
+/// feature, or on platforms core_affinity doesn't support.
+pub fn pin_to_core(id: usize) -> bool {
+    #[cfg(feature = "pin-core")]
+    {
+        // core_affinity indexes a fixed size cpu set with the id, out of
+        // range ids panic rather than fail
+        let known =
+            core_affinity::get_core_ids().is_some_and(|ids| ids.iter().any(|core| core.id == id));
+        known && core_affinity::set_for_current(core_affinity::CoreId { id })
+    }
+    #[cfg(not(feature = "pin-core"))]
+    {
+        let _ = id;
+        false
+    }
+}
+
+// ***************************************************************************
+// Reporting
+// ***************************************************************************
+
+/// Average nanoseconds per operation, `None` when there is nothing to
+/// divide, i.e. no operations or a duration below the clock's resolution.
+pub fn ns_per_op(duration: Duration, ops: usize) -> Option<f64> {
+    if ops == 0 || duration.is_zero() {
+        return None;
+    }
+    Some(duration.as_nanos() as f64 / ops as f64)
+}
+
+/// [`ns_per_op`] formatted to two decimal places, or `n/a`.
+pub fn format_ns_per_op(duration: Duration, ops: usize) -> String {
+    match ns_per_op(duration, ops) {
+        Some(ns) => format!("{:.2}", ns),
+        None => "n/a".to_string(),
+    }
+}
+
+/// The unit to report times in, `Auto` picks whichever reads best.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    Auto,
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    Seconds,
+}
+
+impl std::str::FromStr for Unit {
+    type Err = String;
+
+    /// One of auto, ns, us, ms or s.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Unit::Auto),
+            "ns" => Ok(Unit::Nanoseconds),
+            "us" => Ok(Unit::Microseconds),
+            "ms" => Ok(Unit::Milliseconds),
+            "s" => Ok(Unit::Seconds),
+            _ => Err(format!(
+                "unknown unit '{}', expected one of auto, ns, us, ms, s",
+                s
+            )),
+        }
+    }
+}
+
+/// A time given in nanoseconds, formatted in the unit with its suffix, e.g.
+/// "12.346 ms". `Auto` picks the largest unit the time is at least one of.
+pub fn format_ns(ns: f64, unit: Unit) -> String {
+    let unit = match unit {
+        Unit::Auto if ns >= 1e9 => Unit::Seconds,
+        Unit::Auto if ns >= 1e6 => Unit::Milliseconds,
+        Unit::Auto if ns >= 1e3 => Unit::Microseconds,
+        Unit::Auto => Unit::Nanoseconds,
+        unit => unit,
+    };
+    match unit {
+        Unit::Seconds => format!("{:.3} s", ns / 1e9),
+        Unit::Milliseconds => format!("{:.3} ms", ns / 1e6),
+        Unit::Microseconds => format!("{:.3} us", ns / 1e3),
+        _ => format!("{:.2} ns", ns),
+    }
+}
+
+/// [`format_ns`] for a whole duration.
+pub fn format_time(duration: Duration, unit: Unit) -> String {
+    format_ns(duration.as_nanos() as f64, unit)
+}
+
+/// [`format_ns`] for the time per operation, or `n/a`, see [`ns_per_op`].
+pub fn format_time_per_op(duration: Duration, ops: usize, unit: Unit) -> String {
+    match ns_per_op(duration, ops) {
+        Some(ns) => format_ns(ns, unit),
+        None => "n/a".to_string(),
+    }
+}
+
+/// Each duration as a multiple of the fastest, so the fastest is 1.0 and
+/// anything slower is above it. `None` throughout if the fastest didn't
+/// register on the clock.
+pub fn ratios_to_fastest(durations: &[Duration]) -> Vec<Option<f64>> {
+    let fastest = durations.iter().min().copied().unwrap_or_default();
+    durations
+        .iter()
+        .map(|duration| {
+            if fastest.is_zero() {
+                None
+            } else {
+                Some(duration.as_secs_f64() / fastest.as_secs_f64())
+            }
+        })
+        .collect()
+}
+
+// ***************************************************************************
+// Exporting
+// ***************************************************************************
+
+// criterion keeps each benchmark's results under
+// <dir>/<group>/<name>/new/, estimates.json holding the statistics (in ns
+// per iteration) and benchmark.json its identity. Writing the same layout
+// lets criterion's tooling (critcmp, dashboards, ...) read these results.
+// There's a single measurement per benchmark here, so every estimate is that
+// measurement, with zero spread.
+
+/// Writes a result as criterion's estimates.json and benchmark.json under
+/// `dir/group/name/new/`, creating the directories as needed.
+pub fn write_criterion_estimates(
+    dir: &Path,
+    group: &str,
+    name: &str,
+    ns_per_iter: f64,
+) -> std::io::Result<()> {
+    let new = dir.join(group).join(name).join("new");
+    std::fs::create_dir_all(&new)?;
+    let estimate = |point: f64| {
+        format!(
+            concat!(
+                "{{\"confidence_interval\":{{\"confidence_level\":0.95,",
+                "\"lower_bound\":{point},\"upper_bound\":{point}}},",
+                "\"point_estimate\":{point},\"standard_error\":0.0}}"
+            ),
+            point = point
+        )
+    };
+    let estimates = format!(
+        "{{\"mean\":{mean},\"median\":{mean},\"median_abs_dev\":{zero},\"slope\":null,\"std_dev\":{zero}}}",
+        mean = estimate(ns_per_iter),
+        zero = estimate(0.0)
+    );
+    std::fs::write(new.join("estimates.json"), estimates)?;
+    let full_id = format!("{}/{}", group, name);
+    let benchmark = format!(
+        concat!(
+            "{{\"group_id\":\"{group}\",\"function_id\":\"{name}\",\"value_str\":null,",
+            "\"throughput\":null,\"full_id\":\"{full_id}\",\"directory_name\":\"{full_id}\",",
+            "\"title\":\"{full_id}\"}}"
+        ),
+        group = group,
+        name = name,
+        full_id = full_id
+    );
+    std::fs::write(new.join("benchmark.json"), benchmark)
+}
@@ -0,0 +1,451 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! The isometry example's headline benchmark, composing, inverting and
+//! transforming a point with each representation, callable from code.
+//
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::hint::black_box;
+use std::ops::Mul;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::bench::{ns_per_op, time, Unit};
+use crate::transforms::DEFAULT_EPSILON;
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Similarity3 = nalgebra::geometry::Similarity3<f64>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
+type Matrix4 = nalgebra::base::Matrix4<f64>;
+
+/// A fifth of a nanosecond, a single cycle at 5GHz.
+const MIN_PLAUSIBLE_NS_PER_SAMPLE: f64 = 0.2;
+
+// ***************************************************************************
+// Configuration
+// ***************************************************************************
+
+/// What to run, see [`run`], along with how the isometry example should
+/// run and report it, one field per command line flag.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Samples per representation, `--sub-samples <n>`.
+    pub sub_samples: usize,
+    /// Keys of the representations to time, see [`REGISTRY`]. They run in
+    /// the registry's order, unknown keys are ignored. `--only <names>`.
+    pub representations: Vec<&'static str>,
+    /// The two poses composed in every sample, converted into each
+    /// representation.
+    pub operands: (Isometry3, Isometry3),
+    /// Report time per op across a range of sample counts, `--sweep`.
+    pub sweep: bool,
+    /// Run each representation for this long rather than for sub_samples,
+    /// `--duration <s>`, always positive.
+    pub duration: Option<Duration>,
+    /// Check the representations agree on every sample first, `--verify`.
+    pub verify: bool,
+    /// Check the generated rotations are valid first, `--strict`.
+    pub strict: bool,
+    /// The unit to report times in, `--unit <unit>`.
+    pub unit: Unit,
+    /// Core to pin the measuring thread to, `--pin-core <id>`.
+    pub pin_core: Option<usize>,
+    /// List the representations and flags instead of running, `--list`.
+    pub list: bool,
+    /// Where to also write the headline results as criterion estimates,
+    /// `--criterion-json <dir>`, not with sweep or duration.
+    pub criterion_json: Option<PathBuf>,
+    /// Print poses in full as well as compactly, and log per-sample
+    /// timings, `--verbose`.
+    pub verbose: bool,
+}
+
+impl Default for Config {
+    /// Every representation, 10M samples, the example's two poses, no
+    /// flags.
+    fn default() -> Self {
+        Config {
+            sub_samples: 10_000_000,
+            representations: REGISTRY.iter().map(|registered| registered.key).collect(),
+            operands: (
+                Isometry3::new(
+                    Vector3::new(1.0, 0.0, 0.0),
+                    Vector3::y() * std::f64::consts::FRAC_PI_2,
+                ),
+                Isometry3::new(
+                    Vector3::new(1.0, 2.0, 3.0),
+                    Vector3::y() * std::f64::consts::FRAC_PI_2,
+                ),
+            ),
+            sweep: false,
+            duration: None,
+            verify: false,
+            strict: false,
+            unit: Unit::Auto,
+            pin_core: None,
+            list: false,
+            criterion_json: None,
+            verbose: false,
+        }
+    }
+}
+
+impl Config {
+    /// The defaults, with the flags given on the command line (program name
+    /// first, as from `std::env::args`) applied. An unknown flag, or a
+    /// missing or malformed value, is an error.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut config = Config::default();
+        let mut args = args.into_iter().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = |expected: &str| {
+                args.next()
+                    .ok_or_else(|| format!("{} expects {}", arg, expected))
+            };
+            match arg.as_str() {
+                "--sub-samples" => {
+                    let value = value("a number of samples")?;
+                    config.sub_samples = value.parse().map_err(|_| {
+                        format!("--sub-samples expects a number of samples, got '{}'", value)
+                    })?;
+                }
+                "--only" => {
+                    config.representations =
+                        parse_only(&value("a comma separated list of representations")?)?;
+                }
+                "--duration" => {
+                    config.duration = Some(parse_duration(&value("a number of seconds")?)?)
+                }
+                "--unit" => config.unit = value("a unit")?.parse()?,
+                "--pin-core" => {
+                    let value = value("a core id")?;
+                    let id = value
+                        .parse()
+                        .map_err(|_| format!("--pin-core expects a core id, got '{}'", value))?;
+                    config.pin_core = Some(id);
+                }
+                "--criterion-json" => {
+                    config.criterion_json = Some(PathBuf::from(value("a directory")?))
+                }
+                "--sweep" => config.sweep = true,
+                "--verify" => config.verify = true,
+                "--strict" => config.strict = true,
+                "--list" => config.list = true,
+                "--verbose" => config.verbose = true,
+                _ => return Err(format!("unknown argument '{}', see --list", arg)),
+            }
+        }
+        // the sweep and throughput modes have no headline results to export
+        if config.criterion_json.is_some() && (config.sweep || config.duration.is_some()) {
+            return Err(
+                "--criterion-json can't be combined with --sweep or --duration".to_string(),
+            );
+        }
+        Ok(config)
+    }
+
+    /// The registry entries selected, in the registry's order.
+    pub fn selected(&self) -> Vec<Registered> {
+        REGISTRY
+            .iter()
+            .copied()
+            .filter(|registered| self.representations.contains(&registered.key))
+            .collect()
+    }
+}
+
+/// Parses a `--duration` value, rejecting anything that wouldn't run a
+/// single sample (zero, negative, NaN), which has no rate to report.
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    match value
+        .parse::<f64>()
+        .ok()
+        .and_then(|s| Duration::try_from_secs_f64(s).ok())
+    {
+        Some(duration) if !duration.is_zero() => Ok(duration),
+        _ => Err(format!(
+            "--duration expects a positive number of seconds, got '{}'",
+            value
+        )),
+    }
+}
+
+/// Parses an `--only` value, e.g. "iso,isomat", into the registry keys it
+/// names.
+fn parse_only(value: &str) -> Result<Vec<&'static str>, String> {
+    let names: Vec<&str> = value.split(',').map(str::trim).collect();
+    if let Some(unknown) = names
+        .iter()
+        .find(|name| !REGISTRY.iter().any(|registered| registered.key == **name))
+    {
+        let known: Vec<&str> = REGISTRY.iter().map(|registered| registered.key).collect();
+        return Err(format!(
+            "unknown representation '{}' for --only, expected any of {}",
+            unknown,
+            known.join(", ")
+        ));
+    }
+    Ok(REGISTRY
+        .iter()
+        .map(|registered| registered.key)
+        .filter(|key| names.contains(key))
+        .collect())
+}
+
+/// One representation in the registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registered {
+    /// As selected with --only.
+    pub key: &'static str,
+    /// As printed in the results.
+    pub name: &'static str,
+    /// What it's good at, for --list.
+    pub description: &'static str,
+    /// Bytes per value.
+    pub size: usize,
+}
+
+// ***************************************************************************
+// Results
+// ***************************************************************************
+
+/// How long one representation took, along with the final point it computed
+/// so the result can be sanity checked afterwards.
+#[derive(Copy, Clone, Debug)]
+pub struct BenchResult {
+    pub key: &'static str,
+    pub name: &'static str,
+    pub duration: Duration,
+    pub last: Point3,
+    /// Samples where the inverse couldn't be computed (and was skipped),
+    /// only ever nonzero for the general affine Transform.
+    pub failed_inversions: usize,
+}
+
+impl BenchResult {
+    /// Whether the sampled result is made of ordinary numbers, i.e. not
+    /// NaN, infinite or denormal.
+    pub fn is_well_formed(&self) -> bool {
+        self.last.iter().all(|c| c.is_finite() && !c.is_subnormal())
+    }
+
+    /// Whether the compiler has likely elided the work, i.e. the sink never
+    /// saw a result, or each sample took less than a clock cycle (no cpu
+    /// composes, inverts and transforms that fast).
+    pub fn looks_optimized_away(&self, sub_samples: usize) -> bool {
+        self.last == Point3::origin()
+            || !ns_per_op(self.duration, sub_samples)
+                .is_some_and(|ns| ns >= MIN_PLAUSIBLE_NS_PER_SAMPLE)
+    }
+}
+
+// ***************************************************************************
+// Representations
+// ***************************************************************************
+
+/// What the headline benchmark needs from a representation, beyond
+/// composing with `*`.
+trait Representation: Copy + Mul<Output = Self> {
+    /// None where the representation can't guarantee an inverse exists.
+    fn checked_inverse(&self) -> Option<Self>;
+    fn apply(&self, p: &Point3) -> Point3;
+    /// As a homogeneous matrix, to compare across representations.
+    fn homogeneous(&self) -> Matrix4;
+}
+
+impl Representation for Transform3 {
+    fn checked_inverse(&self) -> Option<Self> {
+        self.try_inverse()
+    }
+    fn apply(&self, p: &Point3) -> Point3 {
+        self.transform_point(p)
+    }
+    fn homogeneous(&self) -> Matrix4 {
+        self.to_homogeneous()
+    }
+}
+
+impl Representation for Isometry3 {
+    fn checked_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+    fn apply(&self, p: &Point3) -> Point3 {
+        self * p
+    }
+    fn homogeneous(&self) -> Matrix4 {
+        self.to_homogeneous()
+    }
+}
+
+impl Representation for IsometryMatrix3 {
+    fn checked_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+    fn apply(&self, p: &Point3) -> Point3 {
+        self * p
+    }
+    fn homogeneous(&self) -> Matrix4 {
+        self.to_homogeneous()
+    }
+}
+
+impl Representation for Similarity3 {
+    fn checked_inverse(&self) -> Option<Self> {
+        Some(self.inverse())
+    }
+    fn apply(&self, p: &Point3) -> Point3 {
+        self * p
+    }
+    fn homogeneous(&self) -> Matrix4 {
+        self.to_homogeneous()
+    }
+}
+
+/// The timed loop, returning (duration, last point, failed inversions).
+fn time_representation<T: Representation>(
+    a: T,
+    b: T,
+    sub_samples: usize,
+) -> (Duration, Point3, usize) {
+    let mut last = Point3::origin();
+    let mut failed_inversions = 0;
+    let duration = time(sub_samples, |i| {
+        let p = Point3::new(i as f64, 0.0, 0.0);
+        let composed = black_box(black_box(a) * black_box(b));
+        if let Some(inverse) = composed.checked_inverse() {
+            let _ = black_box(black_box(composed) * black_box(inverse));
+        } else {
+            failed_inversions += 1;
+        }
+        last = black_box(black_box(composed).apply(&black_box(p)));
+    });
+    (duration, last, failed_inversions)
+}
+
+/// Every entry within DEFAULT_EPSILON, NaNs never are.
+fn within_epsilon(a: &Matrix4, b: &Matrix4) -> bool {
+    a.iter()
+        .zip(b.iter())
+        .all(|(x, y)| (x - y).abs() <= DEFAULT_EPSILON)
+}
+
+/// The timed loop's arithmetic, unoptimised and checked every sample
+/// against the same steps done with the operand isometries, naming the first
+/// step to diverge.
+fn verify_representation<T: Representation>(
+    (a, b): (T, T),
+    (iso_a, iso_b): (Isometry3, Isometry3),
+    sub_samples: usize,
+) -> Result<(), String> {
+    let expected = iso_a * iso_b;
+    for i in 1..=sub_samples {
+        let p = Point3::new(i as f64, 0.0, 0.0);
+        let composed = a * b;
+        if !within_epsilon(&composed.homogeneous(), &expected.to_homogeneous()) {
+            return Err(format!(
+                "composition diverged at sample {}: {}",
+                i,
+                composed.homogeneous()
+            ));
+        }
+        let Some(inverse) = composed.checked_inverse() else {
+            return Err(format!("inversion failed at sample {}", i));
+        };
+        if !within_epsilon(&(composed * inverse).homogeneous(), &Matrix4::identity()) {
+            return Err(format!(
+                "inversion diverged at sample {}: {}",
+                i,
+                inverse.homogeneous()
+            ));
+        }
+        // absolute error grows with the magnitude of the point
+        let (actual, expected) = (composed.apply(&p), expected * p);
+        if (actual - expected).norm() > DEFAULT_EPSILON * (1.0 + p.coords.norm()) {
+            return Err(format!(
+                "point diverged at sample {}: {:?} != {:?}",
+                i, actual, expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Registers the representations, one `(--only name, display name,
+/// description, type, conversion from the operand isometry)` line each.
+/// Expands to the [`REGISTRY`] along with `time_registered` and
+/// `verify_registered`, which time and verify the named representation.
+macro_rules! bench_reprs {
+    ($(($key:literal, $name:literal, $description:literal, $ty:ty, $convert:expr)),+ $(,)?) => {
+        /// Every representation the benchmark can time, in order.
+        pub const REGISTRY: &[Registered] = &[$(Registered {
+            key: $key,
+            name: $name,
+            description: $description,
+            size: std::mem::size_of::<$ty>(),
+        }),+];
+
+        fn time_registered(registered: &Registered, (a, b): (Isometry3, Isometry3), sub_samples: usize) -> BenchResult {
+            let (duration, last, failed_inversions) = match registered.key {
+                $($key => {
+                    let convert: fn(Isometry3) -> $ty = $convert;
+                    time_representation(convert(a), convert(b), sub_samples)
+                })+
+                _ => unreachable!("not a registered representation: {}", registered.key),
+            };
+            BenchResult { key: registered.key, name: registered.name, duration, last, failed_inversions }
+        }
+
+        fn verify_registered(registered: &Registered, (a, b): (Isometry3, Isometry3), sub_samples: usize) -> Result<(), String> {
+            match registered.key {
+                $($key => {
+                    let convert: fn(Isometry3) -> $ty = $convert;
+                    verify_representation((convert(a), convert(b)), (a, b), sub_samples)
+                })+
+                _ => unreachable!("not a registered representation: {}", registered.key),
+            }
+        }
+    };
+}
+
+bench_reprs! {
+    ("transform", "Transform", "general affine 4x4, inverse can fail", Transform3,
+        |iso| Transform3::from_matrix_unchecked(iso.to_homogeneous())),
+    ("iso", "Isometry", "quaternion + translation, compact, cheap to renormalise", Isometry3,
+        |iso| iso),
+    ("isomat", "IsometryMatrix", "rotation matrix + translation, fastest to apply", IsometryMatrix3,
+        |iso| IsometryMatrix3::from_parts(iso.translation, iso.rotation.to_rotation_matrix())),
+    ("sim", "Similarity", "isometry + uniform scale", Similarity3,
+        |iso| Similarity3::from_isometry(iso, 1.0)),
+}
+
+// ***************************************************************************
+// Running
+// ***************************************************************************
+
+/// Checks every configured representation composes, inverts and transforms
+/// the point as the operand isometries do, on every one of the samples the
+/// benchmark would time. Names the first representation and sample to
+/// diverge.
+pub fn verify(config: &Config) -> Result<(), String> {
+    for registered in config.selected() {
+        verify_registered(&registered, config.operands, config.sub_samples)
+            .map_err(|divergence| format!("{} {}", registered.name, divergence))?;
+    }
+    Ok(())
+}
+
+/// Times the configured representations, in registry order, one result each.
+pub fn run(config: &Config) -> Vec<BenchResult> {
+    config
+        .selected()
+        .iter()
+        .map(|registered| time_registered(registered, config.operands, config.sub_samples))
+        .collect()
+}
@@ -0,0 +1,22 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Helpers shared by the examples.
+//!
+//! Build without default features for a no_std crate that only has the
+//! [`transforms`] math, measurement lives in [`bench`] and the isometry
+//! example's headline benchmark in [`headline`], both need std.
+//
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+// ***************************************************************************
+// Modules
+// ***************************************************************************
+
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "std")]
+pub mod headline;
+pub mod transforms;
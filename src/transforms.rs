@@ -0,0 +1,681 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+//! Rigid transform helpers built on nalgebra's isometries.
+//!
+//! Pure math, no std (only alloc), so this is available to no_std builds.
+//
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+// without std, f64 has no inherent trig / sqrt, these come from libm instead
+#[cfg(not(feature = "std"))]
+use nalgebra::ComplexField;
+
+pub type Point3 = nalgebra::geometry::Point3<f64>;
+pub type Translation3 = nalgebra::geometry::Translation3<f64>;
+pub type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+pub type Quaternion = nalgebra::geometry::UnitQuaternion<f64>;
+pub type Rotation3 = nalgebra::geometry::Rotation3<f64>;
+pub type Matrix3 = nalgebra::base::Matrix3<f64>;
+pub type Matrix4 = nalgebra::base::Matrix4<f64>;
+pub type Matrix6 = nalgebra::base::Matrix6<f64>;
+pub type Vector3 = nalgebra::base::Vector3<f64>;
+pub type Vector4 = nalgebra::base::Vector4<f64>;
+pub type Vector6 = nalgebra::base::Vector6<f64>;
+pub type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
+
+/// Largest deviation from R^T * R = I tolerated when validating rotations.
+const ORTHONORMALITY_TOLERANCE: f64 = 1e-9;
+
+/// Below this |w| a homogeneous point is taken to be at infinity.
+const HOMOGENEOUS_W_TOLERANCE: f64 = 1e-12;
+
+/// Below this rotation angle (radians) the closed forms of exp / log are
+/// swapped for their Taylor expansions to avoid dividing by ~zero.
+const SMALL_ANGLE: f64 = 1e-6;
+
+// ***************************************************************************
+// Errors
+// ***************************************************************************
+
+/// Reasons a raw matrix fails to be a valid isometry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConversionError {
+    NonFinite,
+    NonOrthonormalRotation { error: f64 },
+    BadBottomRow,
+    NonUnitDeterminant { det: f64 },
+}
+
+// Spelled out rather than derived with thiserror, which needs std.
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFinite => write!(f, "matrix contains NaN or infinite entries"),
+            Self::NonOrthonormalRotation { error } => {
+                write!(
+                    f,
+                    "rotation block is not orthonormal (max |R^T * R - I| = {:e})",
+                    error
+                )
+            }
+            Self::BadBottomRow => write!(f, "bottom row is not [0, 0, 0, 1]"),
+            Self::NonUnitDeterminant { det } => {
+                write!(f, "rotation block has determinant {}, expected +1", det)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+// ***************************************************************************
+// Compositions
+// ***************************************************************************
+
+/// Rotation about an arbitrary pivot point, i.e. T(pivot) * R * T(-pivot).
+///
+/// The pivot is a fixed point of the resulting isometry. Same result as
+/// nalgebra's `Isometry3::rotation_wrt_point`, spelled out.
+pub fn rotate_about(pivot: &Point3, rot: &Quaternion) -> Isometry3 {
+    Translation3::from(pivot.coords) * rot * Translation3::from(-pivot.coords)
+}
+
+// ***************************************************************************
+// Views
+// ***************************************************************************
+
+/// The view transform of a camera at `eye` looking at `target`, i.e. the
+/// camera_T_world mapping world points into a right handed camera frame
+/// that looks down its -z axis with +y as close to `up` as possible (the
+/// OpenGL convention). Same result as nalgebra's `Isometry3::look_at_rh`.
+///
+/// `up` must not be parallel to the viewing direction.
+pub fn look_at(eye: &Point3, target: &Point3, up: &Vector3) -> Isometry3 {
+    let forward = (target - eye).normalize();
+    let right = forward.cross(up).normalize();
+    let camera_up = right.cross(&forward);
+    let world_r_camera = Rotation3::from_basis_unchecked(&[right, camera_up, -forward]);
+    let rotation = Quaternion::from_rotation_matrix(&world_r_camera).inverse();
+    Isometry3::from_parts(Translation3::from(-(rotation * eye.coords)), rotation)
+}
+
+// ***************************************************************************
+// Planes
+// ***************************************************************************
+
+/// The plane of points x with normal . x = offset, i.e. offset is the signed
+/// distance of the plane from the origin along its normal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    pub normal: nalgebra::Unit<Vector3>,
+    pub offset: f64,
+}
+
+/// Moves a plane by a rigid motion, so that iso * x lies on the result for
+/// every x on the original.
+///
+/// The normal only rotates, the offset picks up the translation's component
+/// along the new normal. (Unlike points, planes are not moved by multiplying
+/// their [normal; -offset] 4-vector by the homogeneous matrix, that takes the
+/// inverse transpose.)
+pub fn transform_plane(iso: &Isometry3, plane: &Plane) -> Plane {
+    let normal = iso.rotation * plane.normal;
+    Plane {
+        normal,
+        offset: plane.offset + normal.dot(&iso.translation.vector),
+    }
+}
+
+// ***************************************************************************
+// Lie Group
+// ***************************************************************************
+
+// Tangent vectors are ordered [rho; phi], i.e. the linear part first and the
+// angular (scaled axis) part last.
+
+/// The cross product as a matrix, skew(a) * b == a.cross(&b), i.e.
+/// [[0, -z, y], [z, 0, -x], [-y, x, 0]]. Same as nalgebra's `cross_matrix`.
+pub fn skew(v: &Vector3) -> Matrix3 {
+    Matrix3::new(0.0, -v.z, v.y, v.z, 0.0, -v.x, -v.y, v.x, 0.0)
+}
+
+/// The left Jacobian V of SO(3), maps rho to the translation in exp.
+fn left_jacobian(phi: &Vector3) -> Matrix3 {
+    let theta = phi.norm();
+    let skew = skew(phi);
+    if theta < SMALL_ANGLE {
+        return Matrix3::identity() + 0.5 * skew + skew * skew / 6.0;
+    }
+    let theta2 = theta * theta;
+    Matrix3::identity()
+        + (1.0 - theta.cos()) / theta2 * skew
+        + (theta - theta.sin()) / (theta2 * theta) * skew * skew
+}
+
+/// Inverse of the [`left_jacobian`], closed form.
+fn left_jacobian_inverse(phi: &Vector3) -> Matrix3 {
+    let theta = phi.norm();
+    let skew = skew(phi);
+    if theta < SMALL_ANGLE {
+        return Matrix3::identity() - 0.5 * skew + skew * skew / 12.0;
+    }
+    let theta2 = theta * theta;
+    Matrix3::identity() - 0.5 * skew
+        + (1.0 - theta * theta.sin() / (2.0 * (1.0 - theta.cos()))) / theta2 * skew * skew
+}
+
+/// SE(3) exponential map, tangent vector to isometry.
+pub fn exp(xi: &Vector6) -> Isometry3 {
+    let rho: Vector3 = xi.fixed_rows::<3>(0).into_owned();
+    let phi: Vector3 = xi.fixed_rows::<3>(3).into_owned();
+    Isometry3::from_parts(
+        Translation3::from(left_jacobian(&phi) * rho),
+        Quaternion::from_scaled_axis(phi),
+    )
+}
+
+/// SE(3) logarithm, isometry to tangent vector. Inverse of [`exp`] for
+/// rotations of less than pi.
+pub fn log(iso: &Isometry3) -> Vector6 {
+    let phi = iso.rotation.scaled_axis();
+    let rho = left_jacobian_inverse(&phi) * iso.translation.vector;
+    Vector6::new(rho.x, rho.y, rho.z, phi.x, phi.y, phi.z)
+}
+
+/// Perturbs an isometry by a tangent vector in its local frame, iso * exp(delta).
+pub fn box_plus(iso: &Isometry3, delta: &Vector6) -> Isometry3 {
+    iso * exp(delta)
+}
+
+/// The local tangent vector taking b to a, log(b^-1 * a), so that
+/// box_plus(b, box_minus(a, b)) == a.
+pub fn box_minus(a: &Isometry3, b: &Isometry3) -> Vector6 {
+    log(&(b.inverse() * a))
+}
+
+/// The adjoint of an isometry, the 6x6 mapping tangent vectors (twists)
+/// expressed in its local frame into its parent frame,
+/// Ad(iso) * xi == log(iso * exp(xi) * iso^-1).
+///
+/// In the [rho; phi] ordering used here that's [[R, [t]x R], [0, R]].
+pub fn adjoint(iso: &Isometry3) -> Matrix6 {
+    let rotation = iso.rotation.to_rotation_matrix().into_inner();
+    let mut ad = Matrix6::zeros();
+    ad.fixed_view_mut::<3, 3>(0, 0).copy_from(&rotation);
+    ad.fixed_view_mut::<3, 3>(0, 3)
+        .copy_from(&(skew(&iso.translation.vector) * rotation));
+    ad.fixed_view_mut::<3, 3>(3, 3).copy_from(&rotation);
+    ad
+}
+
+/// A rigid body velocity, linear (m/s) and angular (rad/s), i.e. a tangent
+/// vector per unit time.
+///
+/// The frame it's expressed in matters: a body twist is in the moving
+/// body's own frame, a spatial twist is the same motion seen from the fixed
+/// frame, the velocity of the body point currently at the fixed origin.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Twist {
+    pub linear: Vector3,
+    pub angular: Vector3,
+}
+
+impl Twist {
+    pub fn new(linear: Vector3, angular: Vector3) -> Self {
+        Twist { linear, angular }
+    }
+
+    /// From a [rho; phi] ordered tangent vector, see [`exp`].
+    pub fn from_vector(xi: &Vector6) -> Self {
+        Twist::new(
+            xi.fixed_rows::<3>(0).into_owned(),
+            xi.fixed_rows::<3>(3).into_owned(),
+        )
+    }
+
+    /// As a [rho; phi] ordered tangent vector, see [`exp`].
+    pub fn to_vector(&self) -> Vector6 {
+        let (v, w) = (self.linear, self.angular);
+        Vector6::new(v.x, v.y, v.z, w.x, w.y, w.z)
+    }
+
+    /// This body twist of a body at world_T_body, seen from the world frame.
+    pub fn to_spatial(&self, world_t_body: &Isometry3) -> Twist {
+        Twist::from_vector(&(adjoint(world_t_body) * self.to_vector()))
+    }
+
+    /// This spatial twist of a body at world_T_body, in the body's own
+    /// frame. Inverse of [`Twist::to_spatial`].
+    pub fn to_body(&self, world_t_body: &Isometry3) -> Twist {
+        Twist::from_vector(&(adjoint(&world_t_body.inverse()) * self.to_vector()))
+    }
+}
+
+// ***************************************************************************
+// Trajectories
+// ***************************************************************************
+
+/// Consecutive relative motions of an absolute trajectory, i.e. the
+/// odometry increments poses[i]^-1 * poses[i + 1]. One shorter than the input.
+pub fn to_relative(poses: &[Isometry3]) -> Vec<Isometry3> {
+    poses
+        .windows(2)
+        .map(|pair| pair[0].inv_mul(&pair[1]))
+        .collect()
+}
+
+/// Chains relative motions back into a single pose, the product of the
+/// motions in order, summing the translation with Kahan's compensated
+/// summation.
+///
+/// Folding with `*` rounds the running translation at every step, so over a
+/// long chain of small motions the error grows with the chain's length. The
+/// compensation carries each step's rounding error into the next, keeping the
+/// translation within a few ulps regardless. The rotation is multiplied as is.
+pub fn compose_compensated(motions: &[Isometry3]) -> Isometry3 {
+    let mut rotation = Quaternion::identity();
+    let mut translation = Vector3::zeros();
+    let mut compensation = Vector3::zeros();
+    for motion in motions {
+        let step = rotation * motion.translation.vector - compensation;
+        let sum = translation + step;
+        compensation = (sum - translation) - step;
+        translation = sum;
+        rotation *= motion.rotation;
+    }
+    Isometry3::from_parts(Translation3::from(translation), rotation)
+}
+
+/// Timestamped poses, in time order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory {
+    poses: Vec<(f64, Isometry3)>,
+}
+
+impl Trajectory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a pose, timestamps must be finite and strictly increasing.
+    pub fn push(&mut self, timestamp: f64, pose: Isometry3) {
+        assert!(
+            timestamp.is_finite(),
+            "timestamps must be finite, got {}",
+            timestamp
+        );
+        if let Some((last, _)) = self.poses.last() {
+            assert!(
+                timestamp > *last,
+                "timestamps must increase, {} follows {}",
+                timestamp,
+                last
+            );
+        }
+        self.poses.push((timestamp, pose));
+    }
+
+    /// The timestamped poses, in time order.
+    pub fn poses(&self) -> &[(f64, Isometry3)] {
+        &self.poses
+    }
+
+    /// Time from the first pose to the last, zero with fewer than two.
+    pub fn duration(&self) -> f64 {
+        match (self.poses.first(), self.poses.last()) {
+            (Some((first, _)), Some((last, _))) => last - first,
+            _ => 0.0,
+        }
+    }
+
+    /// The pose at time t, interpolated (lerp_slerp) between the poses either
+    /// side of it, or the end pose for times outside the trajectory. `None`
+    /// for an empty trajectory, or a non-finite t.
+    ///
+    /// The rotation takes the shortest arc even if neighbouring quaternions
+    /// are stored in opposite hemispheres, nalgebra's slerp flips the sign.
+    pub fn sample(&self, t: f64) -> Option<Isometry3> {
+        if !t.is_finite() {
+            return None;
+        }
+        let (first, last) = (self.poses.first()?, self.poses.last()?);
+        if t <= first.0 {
+            return Some(first.1);
+        }
+        if t >= last.0 {
+            return Some(last.1);
+        }
+        // the first pose after t, there is one and it's not the first pose
+        let after = self.poses.partition_point(|(timestamp, _)| *timestamp <= t);
+        let ((t0, pose0), (t1, pose1)) = (self.poses[after - 1], self.poses[after]);
+        Some(pose0.lerp_slerp(&pose1, (t - t0) / (t1 - t0)))
+    }
+
+    /// See [`to_relative`].
+    pub fn relative_motions(&self) -> Vec<Isometry3> {
+        let poses: Vec<Isometry3> = self.poses.iter().map(|(_, pose)| *pose).collect();
+        to_relative(&poses)
+    }
+}
+
+// ***************************************************************************
+// Metrics
+// ***************************************************************************
+
+/// [`pose_distance_weighted`] with a radian of rotation counted as a metre.
+pub fn pose_distance(a: &Isometry3, b: &Isometry3) -> f64 {
+    pose_distance_weighted(a, b, 1.0)
+}
+
+/// Distance between two poses combining translation and rotation,
+/// sqrt(|t_a - t_b|^2 + (rotation_weight * angle)^2), where angle is the
+/// geodesic angle between the two rotations and rotation_weight is in
+/// metres per radian. Symmetric, and zero only for identical poses
+/// (given a nonzero weight).
+pub fn pose_distance_weighted(a: &Isometry3, b: &Isometry3, rotation_weight: f64) -> f64 {
+    let translation = (a.translation.vector - b.translation.vector).norm();
+    // atan2 rather than nalgebra's angle_to (acos of w), which loses ~1e-8
+    // radians of precision near zero
+    let delta = a.rotation.rotation_to(&b.rotation);
+    // spelled out as RealField's, f64 has no inherent atan2 without std
+    let angle = 2.0 * nalgebra::RealField::atan2(delta.imag().norm(), delta.w.abs());
+    let rotation = rotation_weight * angle;
+    (translation * translation + rotation * rotation).sqrt()
+}
+
+// ***************************************************************************
+// Comparisons
+// ***************************************************************************
+
+/// The tolerance for comparing the results of a handful of operations on
+/// poses within a few metres / radians of the identity. Long chains and far
+/// flung poses accumulate more rounding and need a looser one.
+pub const DEFAULT_EPSILON: f64 = 1e-12;
+
+/// Whether two isometries are equal within an absolute `epsilon`, per
+/// component: the translations, and the quaternions up to sign (q and -q
+/// being the same rotation). Not scaled by their magnitude, far flung poses
+/// need a looser epsilon.
+pub fn iso_abs_diff_eq(a: &Isometry3, b: &Isometry3, epsilon: f64) -> bool {
+    let (qa, qb) = (a.rotation.coords, b.rotation.coords);
+    (a.translation.vector - b.translation.vector).amax() <= epsilon
+        && f64::min((qa - qb).amax(), (qa + qb).amax()) <= epsilon
+}
+
+// ***************************************************************************
+// Averaging
+// ***************************************************************************
+
+/// Mean of a set of poses, see [`weighted_average`].
+pub fn average(poses: &[Isometry3]) -> Isometry3 {
+    mean(poses.iter().map(|pose| (1.0, pose)))
+}
+
+/// Weighted mean of a set of poses.
+///
+/// Translations are averaged directly. Rotations use Markley's method, the
+/// principal eigenvector of sum(w * q * q^T), which is immune to the q / -q
+/// sign ambiguity that breaks naive component-wise averaging. Weights need
+/// not be normalised. No poses (or no total weight) gives the identity.
+pub fn weighted_average(poses: &[(f64, Isometry3)]) -> Isometry3 {
+    mean(poses.iter().map(|(weight, pose)| (*weight, pose)))
+}
+
+fn mean<'a>(poses: impl Iterator<Item = (f64, &'a Isometry3)>) -> Isometry3 {
+    let mut total = 0.0;
+    let mut translation = Vector3::zeros();
+    let mut accumulator = Matrix4::zeros();
+    for (weight, pose) in poses {
+        total += weight;
+        translation += weight * pose.translation.vector;
+        let q = pose.rotation.coords;
+        accumulator += weight * q * q.transpose();
+    }
+    if total <= 0.0 {
+        return Isometry3::identity();
+    }
+    let eigen = accumulator.symmetric_eigen();
+    let principal = eigen
+        .eigenvectors
+        .column(eigen.eigenvalues.imax())
+        .into_owned();
+    let rotation = Quaternion::from_quaternion(nalgebra::Quaternion::from(principal));
+    Isometry3::from_parts(Translation3::from(translation / total), rotation)
+}
+
+// ***************************************************************************
+// Registration
+// ***************************************************************************
+
+/// The rigid transform best mapping `src` onto `dst` (least squares over
+/// corresponding points, `dst[i] ~ align(src, dst) * src[i]`).
+///
+/// Umeyama's method: centre both sets, take the SVD of their cross
+/// covariance, U * V^T is the rotation (with the smallest singular direction
+/// flipped if that would otherwise be a reflection), the translation then
+/// maps one centroid onto the other. No points gives the identity.
+pub fn align(src: &[Point3], dst: &[Point3]) -> Isometry3 {
+    assert_eq!(src.len(), dst.len(), "alignment needs corresponding points");
+    if src.is_empty() {
+        return Isometry3::identity();
+    }
+    let n = src.len() as f64;
+    let src_centroid = src.iter().fold(Vector3::zeros(), |sum, p| sum + p.coords) / n;
+    let dst_centroid = dst.iter().fold(Vector3::zeros(), |sum, p| sum + p.coords) / n;
+    let covariance = src.iter().zip(dst).fold(Matrix3::zeros(), |sum, (s, d)| {
+        sum + (d.coords - dst_centroid) * (s.coords - src_centroid).transpose()
+    });
+
+    let rotation = nearest_rotation(&covariance);
+    let translation = Translation3::from(dst_centroid - rotation * src_centroid);
+    Isometry3::from_parts(translation, rotation)
+}
+
+// ***************************************************************************
+// Keys
+// ***************************************************************************
+
+/// A pose rounded onto a grid so it can key a `HashMap` / `HashSet`, f64s
+/// being neither `Eq` nor `Hash` (NaN, and -0.0 == 0.0 with different bits).
+///
+/// Holds the translation in units of `translation_step` and the quaternion
+/// (x, y, z, w) in units of `rotation_step`, the quaternion first flipped
+/// into the w >= 0 hemisphere so q and -q, the same rotation, share a key.
+/// Poses within a cell collide, poses further than a step apart don't, but
+/// as with any rounding two arbitrarily close poses either side of a cell
+/// boundary get different keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuantizedPose(pub [i64; 7]);
+
+impl QuantizedPose {
+    pub fn new(iso: &Isometry3, translation_step: f64, rotation_step: f64) -> Self {
+        let t = iso.translation.vector / translation_step;
+        let q = iso.rotation.coords * iso.rotation.w.signum() / rotation_step;
+        let mut key = [0; 7];
+        for (k, value) in key.iter_mut().zip(t.iter().chain(q.iter())) {
+            *k = value.round() as i64;
+        }
+        QuantizedPose(key)
+    }
+}
+
+// ***************************************************************************
+// Conversions
+// ***************************************************************************
+
+/// Checked counterpart to `Transform3::from_matrix_unchecked(iso.to_homogeneous())`.
+///
+/// Rejects matrices with NaN or infinite entries, whose linear block is not
+/// a proper rotation or whose bottom row is not [0, 0, 0, 1].
+pub fn isometry_try_from_matrix(m: &Matrix4) -> Result<Isometry3, ConversionError> {
+    // NaN compares false against every tolerance below, catch it up front
+    if !m.iter().all(|x| x.is_finite()) {
+        return Err(ConversionError::NonFinite);
+    }
+    if m.fixed_view::<1, 4>(3, 0) != nalgebra::RowVector4::new(0.0, 0.0, 0.0, 1.0) {
+        return Err(ConversionError::BadBottomRow);
+    }
+    let linear: Matrix3 = m.fixed_view::<3, 3>(0, 0).into_owned();
+    let error = (linear.transpose() * linear - Matrix3::identity()).amax();
+    if error > ORTHONORMALITY_TOLERANCE {
+        return Err(ConversionError::NonOrthonormalRotation { error });
+    }
+    // orthonormal, but could still be a reflection
+    let det = linear.determinant();
+    if (det - 1.0).abs() > ORTHONORMALITY_TOLERANCE {
+        return Err(ConversionError::NonUnitDeterminant { det });
+    }
+    let rotation = Quaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(linear));
+    let translation = Translation3::from(m.fixed_view::<3, 1>(0, 3).into_owned());
+    Ok(Isometry3::from_parts(translation, rotation))
+}
+
+/// The rotation closest to m, U * V^T from its SVD, with the sign of the
+/// smallest singular direction flipped if that would otherwise be a
+/// reflection.
+fn nearest_rotation(m: &Matrix3) -> Quaternion {
+    let svd = m.svd(true, true);
+    let (mut u, v_t) = (svd.u.unwrap(), svd.v_t.unwrap());
+    if (u * v_t).determinant() < 0.0 {
+        // singular values are sorted, the last is the smallest
+        u.column_mut(2).neg_mut();
+    }
+    Quaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(u * v_t))
+}
+
+/// The rigid transform closest to an affine one, e.g. to clean up drift
+/// accumulated in a long chain of `Transform3` multiplies.
+///
+/// Translation is kept as is, the linear block is replaced by its nearest
+/// rotation (polar decomposition via SVD).
+pub fn nearest_isometry(t: &Transform3) -> Isometry3 {
+    debug_assert!(
+        t.matrix().iter().all(|x| x.is_finite()),
+        "nearest_isometry of a non-finite transform"
+    );
+    let linear: Matrix3 = t.matrix().fixed_view::<3, 3>(0, 0).into_owned();
+    let rotation = nearest_rotation(&linear);
+    let translation = Translation3::from(t.matrix().fixed_view::<3, 1>(0, 3).into_owned());
+    Isometry3::from_parts(translation, rotation)
+}
+
+// C and graphics APIs commonly hand transforms around as flat, row-major
+// arrays of 16, nalgebra is column-major so these transpose on the way
+// through. The translation lives in slots 3, 7 and 11.
+
+/// Flattens an isometry into a row-major 4x4 buffer.
+pub fn row_major_from_isometry(iso: &Isometry3) -> [f64; 16] {
+    let mut buffer = [0.0; 16];
+    // the transpose's column-major storage is the original's row-major order
+    buffer.copy_from_slice(iso.to_homogeneous().transpose().as_slice());
+    buffer
+}
+
+/// Reads an isometry back out of a row-major 4x4 buffer, validated as in
+/// [`isometry_try_from_matrix`].
+pub fn row_major_to_isometry(buffer: &[f64; 16]) -> Result<Isometry3, ConversionError> {
+    isometry_try_from_matrix(&Matrix4::from_row_slice(buffer))
+}
+
+// Homogeneous points are [x, y, z, w], standing for (x / w, y / w, z / w).
+// w = 0 is a point at infinity, i.e. a direction, it picks up no translation
+// from a homogeneous matrix and has no Point3 to come back to.
+
+/// A point as a homogeneous 4-vector, w = 1.
+pub fn to_homogeneous_point(p: &Point3) -> Vector4 {
+    p.to_homogeneous()
+}
+
+/// The point a homogeneous 4-vector stands for, dividing through by w, or
+/// `None` for a point at infinity (w ~ 0).
+pub fn from_homogeneous_point(v: &Vector4) -> Option<Point3> {
+    if v.w.abs() < HOMOGENEOUS_W_TOLERANCE {
+        return None;
+    }
+    Some(Point3::from(v.xyz() / v.w))
+}
+
+// Euler angles here are roll, pitch, yaw in radians, applied as
+// R = Rz(yaw) * Ry(pitch) * Rx(roll), i.e. intrinsic Z-Y'-X'' (yaw about z,
+// then pitch about the new y, then roll about the newest x), the same as
+// extrinsic X-Y-Z and ROS's rpy. At pitch = +-pi / 2 (gimbal lock) roll and
+// yaw become the same axis, only their sum / difference is recoverable.
+
+/// Splits an isometry into its position (x, y, z) and orientation (roll,
+/// pitch, yaw), pitch within [-pi / 2, pi / 2].
+pub fn to_pose_euler(iso: &Isometry3) -> ([f64; 3], [f64; 3]) {
+    let (roll, pitch, yaw) = iso.rotation.euler_angles();
+    let t = iso.translation.vector;
+    ([t.x, t.y, t.z], [roll, pitch, yaw])
+}
+
+/// Builds an isometry from a position (x, y, z) and orientation (roll,
+/// pitch, yaw), see [`to_pose_euler`].
+pub fn from_pose_euler(xyz: &[f64; 3], rpy: &[f64; 3]) -> Isometry3 {
+    Isometry3::from_parts(
+        Translation3::new(xyz[0], xyz[1], xyz[2]),
+        Quaternion::from_euler_angles(rpy[0], rpy[1], rpy[2]),
+    )
+}
+
+/// A compact, one line rendering of a pose for logs and terminals,
+/// `pos=(x, y, z) rpy=(roll, pitch, yaw)` to 3 decimal places, metres and
+/// radians, see [`to_pose_euler`].
+pub fn format_pose(iso: &Isometry3) -> String {
+    let ([x, y, z], [roll, pitch, yaw]) = to_pose_euler(iso);
+    // rounded here, and -0 turned into 0, so values that round to zero
+    // don't print as -0.000
+    let tidy = |v: f64| (v * 1e3).round() / 1e3 + 0.0;
+    let [x, y, z, roll, pitch, yaw] = [x, y, z, roll, pitch, yaw].map(tidy);
+    alloc::format!(
+        "pos=({:.3}, {:.3}, {:.3}) rpy=({:.3}, {:.3}, {:.3})",
+        x,
+        y,
+        z,
+        roll,
+        pitch,
+        yaw
+    )
+}
+
+// mint is the common currency between math crates (cgmath, glam, ...), it
+// has no transform types as such, so an isometry crosses either as a
+// column-major 4x4 or as its translation and rotation parts.
+
+/// An isometry as a mint column-major 4x4.
+#[cfg(feature = "mint")]
+pub fn isometry_to_mint(iso: &Isometry3) -> mint::ColumnMatrix4<f64> {
+    iso.to_homogeneous().into()
+}
+
+/// Reads an isometry back out of a mint column-major 4x4, validated as in
+/// [`isometry_try_from_matrix`].
+#[cfg(feature = "mint")]
+pub fn isometry_from_mint(m: mint::ColumnMatrix4<f64>) -> Result<Isometry3, ConversionError> {
+    isometry_try_from_matrix(&Matrix4::from(m))
+}
+
+/// An isometry as mint translation and rotation parts.
+#[cfg(feature = "mint")]
+pub fn isometry_to_mint_parts(iso: &Isometry3) -> (mint::Vector3<f64>, mint::Quaternion<f64>) {
+    (iso.translation.vector.into(), iso.rotation.into())
+}
+
+/// Reassembles an isometry from mint parts, renormalising the rotation.
+#[cfg(feature = "mint")]
+pub fn isometry_from_mint_parts(
+    translation: mint::Vector3<f64>,
+    rotation: mint::Quaternion<f64>,
+) -> Isometry3 {
+    let rotation = Quaternion::new_normalize(nalgebra::Quaternion::from(rotation));
+    Isometry3::from_parts(Translation3::from(Vector3::from(translation)), rotation)
+}
@@ -0,0 +1,98 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// The bench module's timing arithmetic and formatting, on fixed durations so
+// nothing here depends on how fast the machine is.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::time::Duration;
+
+use rust_examples::bench::{
+    format_ns_per_op, format_time, format_time_per_op, ns_per_op, pin_to_core, ratios_to_fastest,
+    write_criterion_estimates, Unit,
+};
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+fn ns_per_op_divides_by_ops() {
+    assert_eq!(ns_per_op(Duration::from_micros(3), 1000), Some(3.0));
+    assert_eq!(format_ns_per_op(Duration::from_micros(3), 1000), "3.00");
+}
+
+#[test]
+fn ns_per_op_is_not_available_for_zero_duration_or_ops() {
+    assert_eq!(ns_per_op(Duration::ZERO, 1000), None);
+    assert_eq!(ns_per_op(Duration::from_micros(3), 0), None);
+    assert_eq!(format_ns_per_op(Duration::ZERO, 1000), "n/a");
+}
+
+#[test]
+fn ratios_are_relative_to_the_fastest() {
+    let durations = [
+        Duration::from_millis(30),
+        Duration::from_millis(15),
+        Duration::from_millis(60),
+    ];
+    assert_eq!(
+        ratios_to_fastest(&durations),
+        vec![Some(2.0), Some(1.0), Some(4.0)]
+    );
+    assert_eq!(ratios_to_fastest(&[]), vec![]);
+    assert_eq!(
+        ratios_to_fastest(&[Duration::ZERO, Duration::from_millis(1)]),
+        vec![None, None]
+    );
+}
+
+#[test]
+fn times_scale_to_the_unit() {
+    let duration = Duration::from_micros(12_345);
+    assert_eq!(format_time(duration, Unit::Auto), "12.345 ms");
+    assert_eq!(format_time(duration, Unit::Seconds), "0.012 s");
+    assert_eq!(format_time(duration, Unit::Microseconds), "12345.000 us");
+    assert_eq!(format_time(duration, Unit::Nanoseconds), "12345000.00 ns");
+    assert_eq!(
+        format_time_per_op(duration, 1_000_000, Unit::Auto),
+        "12.35 ns"
+    );
+    assert_eq!(format_time_per_op(Duration::ZERO, 1000, Unit::Auto), "n/a");
+}
+
+#[test]
+fn units_parse_from_their_abbreviations() {
+    assert_eq!("auto".parse(), Ok(Unit::Auto));
+    assert_eq!("ns".parse(), Ok(Unit::Nanoseconds));
+    assert_eq!("us".parse(), Ok(Unit::Microseconds));
+    assert_eq!("ms".parse(), Ok(Unit::Milliseconds));
+    assert_eq!("s".parse(), Ok(Unit::Seconds));
+    assert!("minutes".parse::<Unit>().is_err());
+}
+
+#[test]
+fn criterion_estimates_land_in_criterions_layout() {
+    let dir = std::env::temp_dir().join(format!("rust_examples_criterion_{}", std::process::id()));
+    write_criterion_estimates(&dir, "isometry", "Isometry", 12.5).unwrap();
+    let new = dir.join("isometry").join("Isometry").join("new");
+    let estimates = std::fs::read_to_string(new.join("estimates.json")).unwrap();
+    assert!(estimates.starts_with(
+        r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"lower_bound":12.5,"#
+    ));
+    assert!(estimates.contains(r#""point_estimate":12.5,"#));
+    assert!(estimates.contains(r#""slope":null"#));
+    let benchmark = std::fs::read_to_string(new.join("benchmark.json")).unwrap();
+    assert!(benchmark.contains(r#""full_id":"isometry/Isometry""#));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn pinning_to_a_core_that_does_not_exist_fails() {
+    assert!(!pin_to_core(99_999));
+    assert!(!pin_to_core(usize::MAX));
+}
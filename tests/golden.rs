@@ -0,0 +1,168 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// Golden values - a handful of transforms and their compositions / inverses
+// as literal matrices, to hold nalgebra's output against other libraries.
+//
+// Provenance: worked out by hand in closed form (Rodrigues' formula, then
+// block multiplies), only sin / cos of 60 and 90 degrees appear so every
+// entry is 0, +-0.5, +-1, +-sqrt(3)/2 or a sum of those. The conventions are
+// the common ones (active rotations, column vectors, rotation vector =
+// axis * angle), so the rotation blocks are directly comparable with e.g.
+//
+//   scipy: Rotation.from_rotvec([0, 0, pi / 2]).as_matrix()
+//   Eigen: AngleAxisd(M_PI / 2, Vector3d::UnitZ()).toRotationMatrix()
+//
+// The matrices are written row-major, as Matrix4::new takes them.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use approx::assert_relative_eq;
+use nalgebra::{Matrix4, Point3, Vector3};
+use rust_examples::transforms::DEFAULT_EPSILON;
+
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+
+// ***************************************************************************
+// Inputs
+// ***************************************************************************
+
+/// sqrt(3) / 2, i.e. sin(60 degrees)
+const S60: f64 = 0.8660254037844386;
+
+/// 90 degrees about z, translated (1, 2, 3).
+fn a() -> Isometry3 {
+    Isometry3::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::z() * std::f64::consts::FRAC_PI_2,
+    )
+}
+
+/// 60 degrees about x, translated (-1, 0.5, 2).
+fn b() -> Isometry3 {
+    Isometry3::new(
+        Vector3::new(-1.0, 0.5, 2.0),
+        Vector3::x() * std::f64::consts::FRAC_PI_3,
+    )
+}
+
+/// 120 degrees about (1, 1, 1), which cycles the axes x -> y -> z -> x,
+/// translated (0, 0, 1).
+fn c() -> Isometry3 {
+    let angle = 2.0 * std::f64::consts::FRAC_PI_3;
+    Isometry3::new(
+        Vector3::new(0.0, 0.0, 1.0),
+        Vector3::repeat(1.0).normalize() * angle,
+    )
+}
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+fn golden_inputs() {
+    #[rustfmt::skip]
+    let a_expected = Matrix4::new(
+        0.0, -1.0, 0.0, 1.0,
+        1.0,  0.0, 0.0, 2.0,
+        0.0,  0.0, 1.0, 3.0,
+        0.0,  0.0, 0.0, 1.0,
+    );
+    #[rustfmt::skip]
+    let b_expected = Matrix4::new(
+        1.0, 0.0,  0.0, -1.0,
+        0.0, 0.5, -S60,  0.5,
+        0.0, S60,  0.5,  2.0,
+        0.0, 0.0,  0.0,  1.0,
+    );
+    #[rustfmt::skip]
+    let c_expected = Matrix4::new(
+        0.0, 0.0, 1.0, 0.0,
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 1.0,
+        0.0, 0.0, 0.0, 1.0,
+    );
+    assert_relative_eq!(a().to_homogeneous(), a_expected, epsilon = DEFAULT_EPSILON);
+    assert_relative_eq!(b().to_homogeneous(), b_expected, epsilon = DEFAULT_EPSILON);
+    assert_relative_eq!(c().to_homogeneous(), c_expected, epsilon = DEFAULT_EPSILON);
+}
+
+#[test]
+fn golden_compositions() {
+    #[rustfmt::skip]
+    let ab_expected = Matrix4::new(
+        0.0, -0.5, S60, 0.5,
+        1.0,  0.0, 0.0, 1.0,
+        0.0,  S60, 0.5, 5.0,
+        0.0,  0.0, 0.0, 1.0,
+    );
+    #[rustfmt::skip]
+    let bc_expected = Matrix4::new(
+        0.0,  0.0, 1.0, -1.0,
+        0.5, -S60, 0.0,  0.5 - S60,
+        S60,  0.5, 0.0,  2.5,
+        0.0,  0.0, 0.0,  1.0,
+    );
+    #[rustfmt::skip]
+    let abc_expected = Matrix4::new(
+        -0.5, S60, 0.0, 0.5 + S60,
+         0.0, 0.0, 1.0, 1.0,
+         S60, 0.5, 0.0, 5.5,
+         0.0, 0.0, 0.0, 1.0,
+    );
+    assert_relative_eq!(
+        (a() * b()).to_homogeneous(),
+        ab_expected,
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(
+        (b() * c()).to_homogeneous(),
+        bc_expected,
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(
+        (a() * b() * c()).to_homogeneous(),
+        abc_expected,
+        epsilon = DEFAULT_EPSILON
+    );
+    // (1, 2, 3) through a * b * c lands on (3 * sqrt(3) / 2, 4, sqrt(3) / 2 + 6.5)
+    assert_relative_eq!(
+        a() * b() * c() * Point3::new(1.0, 2.0, 3.0),
+        Point3::new(3.0 * S60, 4.0, S60 + 6.5),
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn golden_inverses() {
+    // the inverse translation is -R^T * t
+    #[rustfmt::skip]
+    let b_inverse_expected = Matrix4::new(
+        1.0,  0.0, 0.0,  1.0,
+        0.0,  0.5, S60, -0.25 - 2.0 * S60,
+        0.0, -S60, 0.5,  0.5 * S60 - 1.0,
+        0.0,  0.0, 0.0,  1.0,
+    );
+    assert_relative_eq!(
+        b().inverse().to_homogeneous(),
+        b_inverse_expected,
+        epsilon = DEFAULT_EPSILON
+    );
+    // c is a pure axis cycle, so its inverse is the transpose, the reverse cycle
+    #[rustfmt::skip]
+    let c_inverse_expected = Matrix4::new(
+        0.0, 1.0, 0.0,  0.0,
+        0.0, 0.0, 1.0, -1.0,
+        1.0, 0.0, 0.0,  0.0,
+        0.0, 0.0, 0.0,  1.0,
+    );
+    assert_relative_eq!(
+        c().inverse().to_homogeneous(),
+        c_inverse_expected,
+        epsilon = DEFAULT_EPSILON
+    );
+}
@@ -0,0 +1,216 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// The isometry example's headline benchmark, run directly rather than
+// through the example's output. Small sample counts, these check what comes
+// back, not how fast.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use approx::assert_relative_eq;
+use rust_examples::bench::Unit;
+use rust_examples::headline::{run, verify, Config, REGISTRY};
+use rust_examples::transforms::DEFAULT_EPSILON;
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+fn run_returns_the_selected_representations_in_registry_order() {
+    let config = Config {
+        sub_samples: 100,
+        representations: vec!["sim", "iso", "unknown"],
+        ..Config::default()
+    };
+    let results = run(&config);
+    let keys: Vec<&str> = results.iter().map(|result| result.key).collect();
+    assert_eq!(keys, ["iso", "sim"]);
+    assert_eq!(
+        REGISTRY
+            .iter()
+            .filter(|registered| registered.key == "iso")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn run_lands_every_representation_on_the_same_point() {
+    let config = Config {
+        sub_samples: 100,
+        ..Config::default()
+    };
+    let results = run(&config);
+    assert_eq!(results.len(), REGISTRY.len());
+    let (a, b) = config.operands;
+    let expected = a * b * nalgebra::Point3::new(100.0, 0.0, 0.0);
+    for result in &results {
+        assert!(
+            result.is_well_formed(),
+            "{}: {:?}",
+            result.name,
+            result.last
+        );
+        assert_eq!(result.failed_inversions, 0, "{}", result.name);
+        assert_relative_eq!(result.last, expected, epsilon = DEFAULT_EPSILON);
+    }
+}
+
+#[test]
+fn verify_passes_for_every_representation() {
+    let config = Config {
+        sub_samples: 100,
+        ..Config::default()
+    };
+    assert_eq!(verify(&config), Ok(()));
+}
+
+#[test]
+fn verify_names_the_representation_that_diverges() {
+    // a NaN operand poisons every representation, the first selected is
+    // the one reported
+    let mut poisoned = nalgebra::Isometry3::identity();
+    poisoned.translation.vector.x = f64::NAN;
+    let config = Config {
+        sub_samples: 10,
+        representations: vec!["iso", "isomat"],
+        operands: (poisoned, poisoned),
+        ..Config::default()
+    };
+    let error = verify(&config).unwrap_err();
+    assert!(error.starts_with("Isometry "), "{}", error);
+}
+
+#[test]
+fn config_defaults_match_the_example() {
+    // the example's hard coded values before they moved into Config
+    let config = Config::default();
+    assert_eq!(config.sub_samples, 10_000_000);
+    assert_eq!(
+        config.representations,
+        ["transform", "iso", "isomat", "sim"]
+    );
+    let (a, b) = config.operands;
+    assert_relative_eq!(
+        a.rotation.angle(),
+        std::f64::consts::FRAC_PI_2,
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_eq!(a.translation.vector, nalgebra::Vector3::new(1.0, 0.0, 0.0));
+    assert_eq!(b.translation.vector, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn config_from_args_picks_up_only() {
+    let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+    assert_eq!(Config::from_args(args("isometry")), Ok(Config::default()));
+    let config = Config::from_args(args("isometry --only isomat,iso --verify")).unwrap();
+    assert_eq!(config.representations, ["iso", "isomat"]);
+    assert!(Config::from_args(args("isometry --only iso,quaternion"))
+        .unwrap_err()
+        .contains("quaternion"));
+    assert!(Config::from_args(args("isometry --only")).is_err());
+}
+
+#[test]
+fn config_from_args_picks_up_every_flag() {
+    let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+    let config = Config::from_args(args(
+        "isometry --sub-samples 1000 --verify --strict --unit us \
+         --pin-core 2 --list --criterion-json target/criterion --verbose",
+    ))
+    .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            sub_samples: 1000,
+            verify: true,
+            strict: true,
+            unit: Unit::Microseconds,
+            pin_core: Some(2),
+            list: true,
+            criterion_json: Some(PathBuf::from("target/criterion")),
+            verbose: true,
+            ..Config::default()
+        }
+    );
+    // the timing modes, which don't export
+    let config = Config::from_args(args("isometry --sweep --duration 0.5")).unwrap();
+    assert!(config.sweep);
+    assert_eq!(config.duration, Some(Duration::from_millis(500)));
+}
+
+#[test]
+fn config_from_args_rejects_malformed_values() {
+    let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+    for line in [
+        "isometry --sub-samples many",
+        "isometry --sub-samples",
+        "isometry --duration 0",
+        "isometry --duration -1",
+        "isometry --duration NaN",
+        "isometry --unit fortnights",
+        "isometry --pin-core first",
+        "isometry --criterion-json",
+        "isometry --sweep --criterion-json out",
+        "isometry --criterion-json out --duration 1",
+    ] {
+        assert!(Config::from_args(args(line)).is_err(), "{}", line);
+    }
+}
+
+#[test]
+fn config_from_args_rejects_unknown_flags() {
+    let args = |line: &str| line.split(' ').map(String::from).collect::<Vec<_>>();
+    // typos must not fall back to the full default run
+    let error = Config::from_args(args("isometry --verfy")).unwrap_err();
+    assert!(error.contains("--verfy"), "{}", error);
+    let error = Config::from_args(args("isometry --onyl iso")).unwrap_err();
+    assert!(error.contains("--onyl"), "{}", error);
+}
+
+#[test]
+fn black_box_leaves_the_results_bit_identical() {
+    // black_box is an optimisation barrier, it hands back its argument
+    // untouched, so the heavily wrapped benchmark loops do the same math
+    use std::hint::black_box;
+    let (a, b) = Config::default().operands;
+    let p = nalgebra::Point3::new(7.0, -1.0, 0.5);
+    assert_eq!(black_box(black_box(a) * black_box(b)), a * b);
+    assert_eq!(black_box(black_box(a * b).inverse()), (a * b).inverse());
+    assert_eq!(black_box(black_box(a * b) * black_box(p)), a * b * p);
+    let (ta, tb) = (a.to_homogeneous(), b.to_homogeneous());
+    assert_eq!(black_box(black_box(ta) * black_box(tb)), ta * tb);
+    assert_eq!(
+        black_box(black_box(ta * tb).try_inverse()),
+        (ta * tb).try_inverse()
+    );
+    // bit for bit, not just ==, which would let -0.0 through as 0.0
+    let bits = |iso: nalgebra::Isometry3<f64>| iso.to_homogeneous().map(f64::to_bits);
+    assert_eq!(bits(black_box(black_box(a) * black_box(b))), bits(a * b));
+}
+
+#[test]
+fn runs_with_the_same_config_agree_on_everything_but_the_timing() {
+    // no seed to fix, the inputs are the config's operands, so two runs
+    // must compute exactly the same thing
+    let config = Config {
+        sub_samples: 1000,
+        ..Config::default()
+    };
+    let (first, second) = (run(&config), run(&config));
+    assert_eq!(first.len(), second.len());
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(
+            (a.key, a.last, a.failed_inversions),
+            (b.key, b.last, b.failed_inversions)
+        );
+    }
+}
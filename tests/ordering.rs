@@ -0,0 +1,54 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// The performance narrative of the isometry example as a falsifiable check.
+//
+// Timing sensitive, so ignored by default, run it by hand on a quiet machine
+// with optimisations on:
+//
+//   cargo test --release --test ordering -- --ignored
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use std::time::Duration;
+
+use rust_examples::headline::{run, Config};
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+#[ignore = "timing sensitive, run by hand with --release"]
+fn representations_rank_as_documented() {
+    // the example's headline loop, compose, invert and transform a point
+    let config = Config {
+        sub_samples: 5_000_000,
+        representations: vec!["transform", "iso", "isomat"],
+        ..Config::default()
+    };
+    let results = run(&config);
+    let duration = |key: &str| -> Duration {
+        results
+            .iter()
+            .find(|result| result.key == key)
+            .unwrap()
+            .duration
+    };
+    let (transform, isometry, isometry_matrix) =
+        (duration("transform"), duration("iso"), duration("isomat"));
+
+    // the rotation matrix makes composing and transforming cheap and the
+    // inverse a transpose, the quaternion pays more per compose / transform,
+    // the general 4x4 pays for a full inverse
+    assert!(
+        isometry_matrix < isometry && isometry < transform,
+        "expected IsometryMatrix < Isometry < Transform, got {:?} / {:?} / {:?}",
+        isometry_matrix,
+        isometry,
+        transform
+    );
+}
@@ -0,0 +1,81 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// Randomised properties of the isometry representations, over arbitrary
+// poses rather than the handful picked by hand elsewhere. Failures shrink to
+// a minimal counterexample, and proptest saves it in proptest-regressions/.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use approx::relative_eq;
+use proptest::prelude::*;
+use rust_examples::transforms::{iso_abs_diff_eq, skew, DEFAULT_EPSILON};
+
+type Point3 = nalgebra::geometry::Point3<f64>;
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
+type Vector3 = nalgebra::base::Vector3<f64>;
+
+/// The poses below reach a few hundred metres out, where rounding is
+/// proportionally larger.
+const FAR_EPSILON: f64 = 10.0 * DEFAULT_EPSILON;
+
+// ***************************************************************************
+// Strategies
+// ***************************************************************************
+
+fn vector(range: f64) -> impl Strategy<Value = Vector3> {
+    (-range..range, -range..range, -range..range).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Translations within 100m of the origin, any rotation of less than pi
+/// (the scaled axis components are below pi / sqrt(3)).
+fn isometry() -> impl Strategy<Value = Isometry3> {
+    (vector(100.0), vector(1.8))
+        .prop_map(|(translation, axisangle)| Isometry3::new(translation, axisangle))
+}
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn inverse_round_trips(iso in isometry()) {
+        prop_assert!(iso_abs_diff_eq(&(iso * iso.inverse()), &Isometry3::identity(), DEFAULT_EPSILON));
+        prop_assert!(iso_abs_diff_eq(&iso.inverse().inverse(), &iso, DEFAULT_EPSILON));
+    }
+
+    #[test]
+    fn composition_is_associative(a in isometry(), b in isometry(), c in isometry()) {
+        prop_assert!(iso_abs_diff_eq(&((a * b) * c), &(a * (b * c)), FAR_EPSILON));
+    }
+
+    #[test]
+    fn identity_is_neutral(iso in isometry()) {
+        prop_assert_eq!(iso * Isometry3::identity(), iso);
+        prop_assert_eq!(Isometry3::identity() * iso, iso);
+    }
+
+    #[test]
+    fn representations_agree(iso in isometry(), p in vector(100.0)) {
+        let p = Point3::from(p);
+        let isom = IsometryMatrix3::from_parts(iso.translation, iso.rotation.to_rotation_matrix());
+        let transform = Transform3::from_matrix_unchecked(iso.to_homogeneous());
+        let expected = iso * p;
+        prop_assert!(relative_eq!(isom * p, expected, epsilon = FAR_EPSILON));
+        prop_assert!(relative_eq!(transform.transform_point(&p), expected, epsilon = FAR_EPSILON));
+    }
+
+    #[test]
+    fn skew_is_the_cross_product(a in vector(100.0), b in vector(100.0)) {
+        prop_assert!(relative_eq!(skew(&a) * b, a.cross(&b), epsilon = DEFAULT_EPSILON));
+        prop_assert_eq!(skew(&a).transpose(), -skew(&a));
+    }
+}
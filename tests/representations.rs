@@ -0,0 +1,200 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// Properties of the nalgebra representations compared in the isometry
+// example.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use approx::assert_relative_eq;
+use nalgebra::{Unit, Vector3};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rust_examples::transforms::DEFAULT_EPSILON;
+
+type Isometry3 = nalgebra::geometry::Isometry3<f64>;
+type IsometryMatrix3 = nalgebra::geometry::IsometryMatrix3<f64>;
+type Rotation3 = nalgebra::geometry::Rotation3<f64>;
+type Quaternion = nalgebra::geometry::UnitQuaternion<f64>;
+type Similarity3 = nalgebra::geometry::Similarity3<f64>;
+type Transform3 = nalgebra::geometry::Transform<f64, nalgebra::TAffine, 3>;
+
+/// Ten thousand compositions in a row, each adding its own rounding.
+const DRIFT_EPSILON: f64 = 1000.0 * DEFAULT_EPSILON;
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+fn composing_with_identity_is_exact() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    // quaternion * identity only ever multiplies by 1 and adds 0, no drift
+    assert_eq!(iso * Isometry3::identity(), iso);
+    assert_eq!(Isometry3::identity() * iso, iso);
+
+    // the homogeneous multiply is exact too, but only because every product
+    // against the identity is by exactly 1 or 0 - not because the
+    // representation keeps the rotation block orthonormal
+    let transform = Transform3::from_matrix_unchecked(iso.to_homogeneous());
+    assert_eq!(transform * Transform3::identity(), transform);
+    assert_eq!(Transform3::identity() * transform, transform);
+}
+
+#[test]
+fn axis_angle_construction_agrees_between_quaternion_and_rotation() {
+    // the example builds q1 and r1 from the same axis-angle, which is only a
+    // fair comparison if they really are the same rotation
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let axisangle =
+            Vector3::from_fn(|_, _| rng.gen_range(-std::f64::consts::PI..std::f64::consts::PI));
+        let q = Quaternion::new(axisangle);
+        let r = Rotation3::new(axisangle);
+        assert_relative_eq!(q.to_rotation_matrix(), r, epsilon = DEFAULT_EPSILON);
+    }
+}
+
+#[test]
+fn unit_axis_construction_agrees_with_scaled_axis() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let axis = Unit::new_normalize(Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0)));
+        let angle = rng.gen_range(-std::f64::consts::PI..std::f64::consts::PI);
+        let scaled_axis = axis.into_inner() * angle;
+        assert_relative_eq!(
+            Quaternion::from_axis_angle(&axis, angle),
+            Quaternion::new(scaled_axis),
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_relative_eq!(
+            Rotation3::from_axis_angle(&axis, angle),
+            Rotation3::new(scaled_axis),
+            epsilon = DEFAULT_EPSILON
+        );
+    }
+}
+
+#[test]
+fn from_parts_rotates_then_translates() {
+    // i.e. p -> R * p + t, the translation is applied after (and is not
+    // itself rotated by) the rotation
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let translation = Vector3::from_fn(|_, _| rng.gen_range(-10.0..10.0));
+        let rotation = Quaternion::new(Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0)));
+        let p = nalgebra::Point3::from(Vector3::from_fn(|_, _| rng.gen_range(-10.0..10.0)));
+
+        let iso = Isometry3::from_parts(translation.into(), rotation);
+        assert_relative_eq!(
+            iso * p,
+            rotation * p + translation,
+            epsilon = DEFAULT_EPSILON
+        );
+    }
+}
+
+#[test]
+fn long_chains_stay_proper_rotations() {
+    // a determinant of -1 would mean the chain had turned into a reflection
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut iso = Isometry3::identity();
+    let mut isom = IsometryMatrix3::identity();
+    let mut transform = Transform3::identity();
+    for _ in 0..10000 {
+        let translation = Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0));
+        let axisangle = Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0));
+        let step = Isometry3::new(translation, axisangle);
+        iso *= step;
+        isom *= IsometryMatrix3::new(translation, axisangle);
+        transform *= Transform3::from_matrix_unchecked(step.to_homogeneous());
+    }
+    for det in [
+        iso.rotation.to_rotation_matrix().matrix().determinant(),
+        isom.rotation.matrix().determinant(),
+        transform.matrix().fixed_view::<3, 3>(0, 0).determinant(),
+    ] {
+        assert_relative_eq!(det, 1.0, epsilon = DRIFT_EPSILON);
+    }
+}
+
+#[test]
+fn the_origin_lands_on_the_translation() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let translation = Vector3::from_fn(|_, _| rng.gen_range(-10.0..10.0));
+        let axisangle = Vector3::from_fn(|_, _| rng.gen_range(-1.0..1.0));
+        let iso = Isometry3::new(translation, axisangle);
+        let isom = IsometryMatrix3::new(translation, axisangle);
+        let transform = Transform3::from_matrix_unchecked(iso.to_homogeneous());
+
+        let origin = nalgebra::Point3::origin();
+        assert_relative_eq!(
+            iso.transform_point(&origin).coords,
+            translation,
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_relative_eq!(
+            isom.transform_point(&origin).coords,
+            translation,
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_relative_eq!(
+            transform.transform_point(&origin).coords,
+            translation,
+            epsilon = DEFAULT_EPSILON
+        );
+    }
+}
+
+#[test]
+fn transform_composition_is_plain_matrix_multiplication() {
+    // Transform3 is a Matrix4 with a tag, the tag buys checked inverses and
+    // point / vector dispatch, not different arithmetic, the products agree
+    // bit for bit
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let mut pose = || {
+            Isometry3::new(
+                Vector3::from_fn(|_, _| rng.gen_range(-100.0..100.0)),
+                Vector3::from_fn(|_, _| rng.gen_range(-1.8..1.8)),
+            )
+        };
+        let (m1, m2) = (pose().to_homogeneous(), pose().to_homogeneous());
+        let (t1, t2) = (
+            Transform3::from_matrix_unchecked(m1),
+            Transform3::from_matrix_unchecked(m2),
+        );
+        assert_eq!(*(t1 * t2).matrix(), m1 * m2);
+    }
+}
+
+#[test]
+fn unit_scale_similarity_matches_the_isometry() {
+    // the example benchmarks Similarity3::from_isometry(iso, 1.0) alongside
+    // the isometries, the scale must not leak into the results
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let iso = Isometry3::new(
+            Vector3::from_fn(|_, _| rng.gen_range(-100.0..100.0)),
+            Vector3::from_fn(|_, _| rng.gen_range(-1.8..1.8)),
+        );
+        let sim = Similarity3::from_isometry(iso, 1.0);
+        let p = nalgebra::Point3::from(Vector3::from_fn(|_, _| rng.gen_range(-100.0..100.0)));
+        assert_relative_eq!(sim * p, iso * p, epsilon = DEFAULT_EPSILON);
+        assert_relative_eq!(
+            sim.inverse() * p,
+            iso.inverse() * p,
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_relative_eq!(
+            (sim * sim).to_homogeneous(),
+            (iso * iso).to_homogeneous(),
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_eq!((sim * sim).scaling(), 1.0);
+    }
+}
@@ -0,0 +1,631 @@
+// ***************************************************************************
+// About
+// ***************************************************************************
+
+// The transforms module's helpers, each checked against a worked example or
+// an identity it must satisfy, e.g. round trips, inverses and edge cases.
+
+// ***************************************************************************
+// Dependencies
+// ***************************************************************************
+
+use approx::assert_relative_eq;
+use nalgebra::{Unit, Vector3, Vector4, Vector6};
+use rust_examples::transforms::{
+    adjoint, align, average, box_minus, box_plus, compose_compensated, exp, format_pose,
+    from_homogeneous_point, from_pose_euler, iso_abs_diff_eq, isometry_try_from_matrix, log,
+    look_at, nearest_isometry, pose_distance, pose_distance_weighted, rotate_about,
+    row_major_from_isometry, row_major_to_isometry, to_homogeneous_point, to_pose_euler,
+    to_relative, transform_plane, weighted_average, ConversionError, Isometry3, Matrix6, Plane,
+    Point3, QuantizedPose, Quaternion, Trajectory, Transform3, Twist, DEFAULT_EPSILON,
+};
+
+// ***************************************************************************
+// Helpers
+// ***************************************************************************
+
+/// Long composition chains and sums over many samples, each step adding its
+/// own rounding.
+const CHAIN_EPSILON: f64 = 1000.0 * DEFAULT_EPSILON;
+
+/// Asserts two isometries are equal, within DEFAULT_EPSILON unless given.
+macro_rules! assert_iso_eq {
+    ($a:expr, $b:expr) => {
+        assert_iso_eq!($a, $b, DEFAULT_EPSILON)
+    };
+    ($a:expr, $b:expr, $epsilon:expr) => {{
+        let (a, b): (&Isometry3, &Isometry3) = (&$a, &$b);
+        assert!(
+            iso_abs_diff_eq(a, b, $epsilon),
+            "{:?} != {:?} within {:e}",
+            a,
+            b,
+            $epsilon
+        );
+    }};
+}
+
+// ***************************************************************************
+// Tests
+// ***************************************************************************
+
+#[test]
+fn rotate_about_fixes_the_pivot() {
+    let pivot = Point3::new(1.0, 2.0, 3.0);
+    let rot = Quaternion::new(Vector3::new(0.3, -0.2, 0.9));
+    let iso = rotate_about(&pivot, &rot);
+
+    assert!((iso * pivot - pivot).norm() < DEFAULT_EPSILON);
+    assert_iso_eq!(iso, Isometry3::rotation_wrt_point(rot, pivot));
+}
+
+#[test]
+fn isometry_try_from_matrix_round_trips() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let converted = isometry_try_from_matrix(&iso.to_homogeneous()).unwrap();
+    assert_iso_eq!(converted, iso);
+}
+
+#[test]
+fn isometry_try_from_matrix_rejects_shear() {
+    let mut matrix = Isometry3::identity().to_homogeneous();
+    matrix[(0, 1)] = 0.1;
+    assert!(matches!(
+        isometry_try_from_matrix(&matrix),
+        Err(ConversionError::NonOrthonormalRotation { .. })
+    ));
+}
+
+#[test]
+fn isometry_try_from_matrix_rejects_reflection() {
+    let mut matrix = Isometry3::identity().to_homogeneous();
+    matrix[(2, 2)] = -1.0;
+    assert_eq!(
+        isometry_try_from_matrix(&matrix),
+        Err(ConversionError::NonUnitDeterminant { det: -1.0 })
+    );
+}
+
+#[test]
+fn isometry_try_from_matrix_rejects_bad_bottom_row() {
+    let mut matrix = Isometry3::identity().to_homogeneous();
+    matrix[(3, 0)] = 1.0;
+    assert_eq!(
+        isometry_try_from_matrix(&matrix),
+        Err(ConversionError::BadBottomRow)
+    );
+}
+
+#[test]
+fn isometry_try_from_matrix_rejects_non_finite_entries() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    // in the rotation, where NaN would otherwise slip past the tolerances,
+    // and in the translation, which nothing else checks
+    for (index, value) in [
+        ((0, 0), f64::NAN),
+        ((1, 2), f64::INFINITY),
+        ((2, 3), f64::NAN),
+        ((0, 3), f64::NEG_INFINITY),
+    ] {
+        let mut matrix = iso.to_homogeneous();
+        matrix[index] = value;
+        assert_eq!(
+            isometry_try_from_matrix(&matrix),
+            Err(ConversionError::NonFinite)
+        );
+    }
+    let mut buffer = row_major_from_isometry(&iso);
+    buffer[3] = f64::NAN;
+    assert_eq!(
+        row_major_to_isometry(&buffer),
+        Err(ConversionError::NonFinite)
+    );
+}
+
+#[test]
+fn weighted_average_with_equal_weights_is_the_mean() {
+    let a = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 0.2));
+    let b = Isometry3::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 0.4));
+    let mean = weighted_average(&[(3.0, a), (3.0, b)]);
+
+    assert_iso_eq!(mean, average(&[a, b]));
+    assert_relative_eq!(
+        mean.translation.vector,
+        Vector3::new(0.5, 1.0, 0.0),
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(
+        mean.rotation.scaled_axis(),
+        Vector3::new(0.0, 0.0, 0.3),
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn weighted_average_with_a_single_weight_returns_that_pose() {
+    let a = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.3, 0.2));
+    let b = Isometry3::new(Vector3::new(0.0, 2.0, 0.0), Vector3::new(0.1, 0.0, 0.4));
+    assert_iso_eq!(weighted_average(&[(0.0, a), (1.0, b)]), b);
+}
+
+#[test]
+fn log_inverts_exp() {
+    for xi in [
+        Vector6::new(1.0, -2.0, 0.5, 0.3, -0.2, 0.9),
+        Vector6::new(1.0, -2.0, 0.5, 1e-8, 0.0, -1e-8),
+        Vector6::zeros(),
+    ] {
+        assert_relative_eq!(log(&exp(&xi)), xi, epsilon = DEFAULT_EPSILON);
+    }
+}
+
+#[test]
+fn box_minus_inverts_box_plus() {
+    let x = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let delta = Vector6::new(0.01, -0.02, 0.03, 0.002, 0.001, -0.003);
+    assert_relative_eq!(
+        box_minus(&box_plus(&x, &delta), &x),
+        delta,
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn nearest_isometry_of_an_isometry_is_itself() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let transform = Transform3::from_matrix_unchecked(iso.to_homogeneous());
+    assert_iso_eq!(nearest_isometry(&transform), iso);
+}
+
+#[test]
+fn nearest_isometry_of_a_sheared_transform_is_rigid() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let mut matrix = iso.to_homogeneous();
+    matrix[(0, 1)] += 1e-3;
+    matrix[(2, 0)] -= 2e-3;
+    let nearest = nearest_isometry(&Transform3::from_matrix_unchecked(matrix));
+
+    assert!(isometry_try_from_matrix(&nearest.to_homogeneous()).is_ok());
+    assert_eq!(nearest.translation.vector, Vector3::new(1.0, 2.0, 3.0));
+    assert!(nearest.rotation.angle_to(&iso.rotation) < 1e-2);
+}
+
+#[test]
+fn to_relative_motions_chain_back_to_the_trajectory() {
+    let poses: Vec<Isometry3> = (0..10)
+        .map(|i| {
+            Isometry3::new(
+                Vector3::new(i as f64, (i * i) as f64, 0.0),
+                Vector3::z() * i as f64 * 0.1,
+            )
+        })
+        .collect();
+    let relative = to_relative(&poses);
+    assert_eq!(relative.len(), poses.len() - 1);
+
+    let mut pose = poses[0];
+    for (motion, expected) in relative.iter().zip(&poses[1..]) {
+        pose *= motion;
+        assert_iso_eq!(pose, *expected);
+    }
+    assert!(to_relative(&poses[..1]).is_empty());
+}
+
+#[test]
+fn row_major_buffers_round_trip() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let buffer = row_major_from_isometry(&iso);
+    assert_iso_eq!(row_major_to_isometry(&buffer).unwrap(), iso);
+}
+
+#[test]
+fn row_major_buffers_put_the_translation_at_the_end_of_each_row() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let buffer = row_major_from_isometry(&iso);
+    assert_eq!([buffer[3], buffer[7], buffer[11]], [1.0, 2.0, 3.0]);
+    assert_eq!(buffer[12..], [0.0, 0.0, 0.0, 1.0]);
+    // first row of the rotation, not the first column
+    let rotation = iso.rotation.to_rotation_matrix();
+    assert_eq!(
+        buffer[..3],
+        [rotation[(0, 0)], rotation[(0, 1)], rotation[(0, 2)]]
+    );
+}
+
+#[test]
+fn pose_distance_is_zero_to_itself_and_symmetric() {
+    let a = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let b = Isometry3::new(Vector3::new(-1.0, 0.5, 2.0), Vector3::new(0.4, -0.2, 0.1));
+    assert_relative_eq!(pose_distance(&a, &a), 0.0, epsilon = DEFAULT_EPSILON);
+    assert_relative_eq!(
+        pose_distance(&a, &b),
+        pose_distance(&b, &a),
+        epsilon = DEFAULT_EPSILON
+    );
+    assert!(pose_distance(&a, &b) > 0.0);
+}
+
+#[test]
+fn pose_distance_weights_the_rotation() {
+    let a = Isometry3::identity();
+    let b = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), Vector3::z() * 0.5);
+    assert_relative_eq!(
+        pose_distance_weighted(&a, &b, 0.0),
+        3.0,
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(
+        pose_distance_weighted(&a, &b, 8.0),
+        5.0,
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn align_recovers_a_known_transform() {
+    let dst_t_src = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.4, -0.3, 2.0));
+    let src: Vec<Point3> = (0..20)
+        .map(|i| {
+            let i = i as f64;
+            Point3::new(i.sin() * 3.0, (i * 0.7).cos(), i * 0.1)
+        })
+        .collect();
+    let dst: Vec<Point3> = src.iter().map(|p| dst_t_src * p).collect();
+
+    assert_iso_eq!(align(&src, &dst), dst_t_src);
+    assert_eq!(align(&[], &[]), Isometry3::identity());
+}
+
+#[test]
+fn compose_compensated_drifts_less_than_naive_composition() {
+    let n = 100_000;
+    let step = Isometry3::translation(0.1, 0.01, 0.001);
+    let motions = vec![step; n];
+    let expected = Vector3::new(0.1, 0.01, 0.001) * n as f64;
+
+    let naive = motions
+        .iter()
+        .fold(Isometry3::identity(), |pose, motion| pose * motion);
+    let compensated = compose_compensated(&motions);
+    let naive_drift = (naive.translation.vector - expected).norm();
+    let compensated_drift = (compensated.translation.vector - expected).norm();
+    assert!(compensated_drift < naive_drift);
+    assert!(
+        compensated_drift < 100.0 * DEFAULT_EPSILON,
+        "compensated drift {:e}",
+        compensated_drift
+    );
+
+    // with rotation in the chain it still agrees with the naive composition
+    let motions = vec![Isometry3::new(Vector3::new(0.1, 0.0, 0.0), Vector3::z() * 1e-3); 1000];
+    let naive = motions
+        .iter()
+        .fold(Isometry3::identity(), |pose, motion| pose * motion);
+    assert_iso_eq!(compose_compensated(&motions), naive, CHAIN_EPSILON);
+}
+
+#[test]
+fn transform_plane_keeps_points_on_the_plane() {
+    let plane = Plane {
+        normal: Unit::new_normalize(Vector3::new(1.0, 2.0, -0.5)),
+        offset: 1.5,
+    };
+    let iso = Isometry3::new(Vector3::new(3.0, -1.0, 2.0), Vector3::new(0.4, -0.7, 1.1));
+    let moved = transform_plane(&iso, &plane);
+
+    // points on the plane, the foot of the origin plus in-plane offsets
+    let foot = Point3::from(plane.normal.into_inner() * plane.offset);
+    let (u, v) = (
+        plane.normal.cross(&Vector3::z()),
+        plane.normal.cross(&Vector3::x()),
+    );
+    for (a, b) in [(0.0, 0.0), (1.0, 0.0), (-2.0, 3.0), (5.0, -4.0)] {
+        let point = foot + a * u + b * v;
+        assert_relative_eq!(
+            plane.normal.dot(&point.coords),
+            plane.offset,
+            epsilon = DEFAULT_EPSILON
+        );
+        assert_relative_eq!(
+            moved.normal.dot(&(iso * point).coords),
+            moved.offset,
+            epsilon = DEFAULT_EPSILON
+        );
+    }
+    assert_relative_eq!(moved.normal.norm(), 1.0, epsilon = DEFAULT_EPSILON);
+}
+
+#[test]
+fn look_at_puts_the_target_down_the_negative_z_axis() {
+    let eye = Point3::new(1.0, 2.0, 3.0);
+    let target = Point3::new(-2.0, 0.5, 1.0);
+    let up = Vector3::z();
+    let camera_t_world = look_at(&eye, &target, &up);
+
+    assert_relative_eq!(
+        camera_t_world * eye,
+        Point3::origin(),
+        epsilon = DEFAULT_EPSILON
+    );
+    let distance = (target - eye).norm();
+    assert_relative_eq!(
+        camera_t_world * target,
+        Point3::new(0.0, 0.0, -distance),
+        epsilon = DEFAULT_EPSILON
+    );
+    // up stays up, it lands in the camera's y-z plane with positive y
+    let camera_up = camera_t_world * up;
+    assert_relative_eq!(camera_up.x, 0.0, epsilon = DEFAULT_EPSILON);
+    assert!(camera_up.y > 0.0);
+    assert_iso_eq!(camera_t_world, Isometry3::look_at_rh(&eye, &target, &up));
+}
+
+#[cfg(feature = "mint")]
+#[test]
+fn mint_conversions_round_trip() {
+    use rust_examples::transforms::{
+        isometry_from_mint, isometry_from_mint_parts, isometry_to_mint, isometry_to_mint_parts,
+    };
+
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    assert_iso_eq!(isometry_from_mint(isometry_to_mint(&iso)).unwrap(), iso);
+    let (translation, rotation) = isometry_to_mint_parts(&iso);
+    assert_eq!(isometry_from_mint_parts(translation, rotation), iso);
+
+    // mint matrices are column-major, the translation is the last column
+    let matrix = isometry_to_mint(&iso);
+    assert_eq!(
+        [matrix.w.x, matrix.w.y, matrix.w.z, matrix.w.w],
+        [1.0, 2.0, 3.0, 1.0]
+    );
+}
+
+#[test]
+fn trajectory_samples_interpolate_between_poses() {
+    let start = Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::zeros());
+    let middle = Isometry3::new(Vector3::new(2.0, 0.0, 0.0), Vector3::z() * 0.5);
+    let end = Isometry3::new(Vector3::new(2.0, 4.0, 0.0), Vector3::z() * 1.5);
+    let mut trajectory = Trajectory::new();
+    assert_eq!(trajectory.sample(0.0), None);
+    trajectory.push(10.0, start);
+    trajectory.push(11.0, middle);
+    trajectory.push(13.0, end);
+    assert_eq!(trajectory.duration(), 3.0);
+
+    // at, and beyond, the endpoints
+    assert_eq!(trajectory.sample(10.0), Some(start));
+    assert_eq!(trajectory.sample(13.0), Some(end));
+    assert_eq!(trajectory.sample(9.0), Some(start));
+    assert_eq!(trajectory.sample(20.0), Some(end));
+    assert_eq!(trajectory.sample(11.0), Some(middle));
+
+    // midpoints of each segment
+    let halfway = trajectory.sample(10.5).unwrap();
+    assert_relative_eq!(
+        halfway.translation.vector,
+        Vector3::new(1.0, 0.0, 0.0),
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(halfway.rotation.angle(), 0.25, epsilon = DEFAULT_EPSILON);
+    let halfway = trajectory.sample(12.0).unwrap();
+    assert_relative_eq!(
+        halfway.translation.vector,
+        Vector3::new(2.0, 2.0, 0.0),
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(halfway.rotation.angle(), 1.0, epsilon = DEFAULT_EPSILON);
+
+    assert_eq!(
+        trajectory.relative_motions(),
+        to_relative(&[start, middle, end])
+    );
+}
+
+#[test]
+fn trajectory_samples_nothing_at_non_finite_times() {
+    let mut trajectory = Trajectory::new();
+    trajectory.push(0.0, Isometry3::identity());
+    trajectory.push(1.0, Isometry3::translation(1.0, 0.0, 0.0));
+    assert_eq!(trajectory.sample(f64::NAN), None);
+    assert_eq!(trajectory.sample(f64::INFINITY), None);
+    assert_eq!(trajectory.sample(f64::NEG_INFINITY), None);
+}
+
+#[test]
+#[should_panic(expected = "timestamps must be finite")]
+fn trajectory_rejects_non_finite_timestamps() {
+    Trajectory::new().push(f64::NAN, Isometry3::identity());
+}
+
+#[test]
+fn euler_round_trips_away_from_gimbal_lock() {
+    let xyz = [1.0, -2.0, 3.0];
+    let rpy = [0.3, -1.2, 2.5];
+    let (xyz_out, rpy_out) = to_pose_euler(&from_pose_euler(&xyz, &rpy));
+    assert_eq!(xyz_out, xyz);
+    for (out, expected) in rpy_out.iter().zip(rpy) {
+        assert_relative_eq!(*out, expected, epsilon = DEFAULT_EPSILON);
+    }
+
+    // yaw turns x towards y, roll is applied first (intrinsically last)
+    let yawed = from_pose_euler(&[0.0; 3], &[0.0, 0.0, std::f64::consts::FRAC_PI_2]);
+    assert_relative_eq!(
+        yawed * Vector3::x(),
+        Vector3::y(),
+        epsilon = DEFAULT_EPSILON
+    );
+    let quarter = std::f64::consts::FRAC_PI_2;
+    let rolled_then_yawed = from_pose_euler(&[0.0; 3], &[quarter, 0.0, quarter]);
+    assert_relative_eq!(
+        rolled_then_yawed * Vector3::y(),
+        Vector3::z(),
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn homogeneous_points_round_trip_through_a_homogeneous_matrix() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let p = Point3::new(-4.0, 5.0, 0.5);
+    let v = to_homogeneous_point(&p);
+    assert_eq!(v, Vector4::new(-4.0, 5.0, 0.5, 1.0));
+    assert_relative_eq!(
+        from_homogeneous_point(&(iso.to_homogeneous() * v)).unwrap(),
+        iso * p,
+        epsilon = DEFAULT_EPSILON
+    );
+    // any nonzero scale stands for the same point
+    assert_relative_eq!(
+        from_homogeneous_point(&(v * -2.5)).unwrap(),
+        p,
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn homogeneous_points_at_infinity_have_no_point3() {
+    let direction = Vector4::new(1.0, 0.0, 0.0, 0.0);
+    assert_eq!(from_homogeneous_point(&direction), None);
+    // and stay at infinity, untranslated, through a homogeneous matrix
+    let iso = Isometry3::translation(1.0, 2.0, 3.0);
+    assert_eq!(iso.to_homogeneous() * direction, direction);
+}
+
+#[test]
+fn trajectory_samples_take_the_shortest_arc() {
+    // 2.5 rad apart about z, the end stored in the opposite hemisphere
+    let start = Isometry3::new(Vector3::zeros(), Vector3::z() * 0.5);
+    let rotation = Quaternion::new(Vector3::z() * 3.0);
+    let flipped = Quaternion::new_unchecked(-rotation.into_inner());
+    let end = Isometry3::from_parts(Vector3::new(1.0, 0.0, 0.0).into(), flipped);
+    assert!(start.rotation.coords.dot(&end.rotation.coords) < 0.0);
+    let mut trajectory = Trajectory::new();
+    trajectory.push(0.0, start);
+    trajectory.push(1.0, end);
+
+    // the long way round would sweep 2 * pi - 2.5
+    let swept: f64 = (0..100)
+        .map(|i| {
+            let a = trajectory.sample(i as f64 / 100.0).unwrap();
+            let b = trajectory.sample((i + 1) as f64 / 100.0).unwrap();
+            a.rotation.angle_to(&b.rotation)
+        })
+        .sum();
+    assert!(swept <= std::f64::consts::PI);
+    assert_relative_eq!(swept, 2.5, epsilon = CHAIN_EPSILON);
+}
+
+#[test]
+fn quantized_poses_collide_only_when_close() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let key = QuantizedPose::new(&iso, 1e-3, 1e-4);
+    let nudged = Isometry3::new(
+        Vector3::new(1.0, 2.0, 3.0 + 1e-5),
+        Vector3::new(0.1, 0.2, 0.3 + 1e-6),
+    );
+    assert_eq!(QuantizedPose::new(&nudged, 1e-3, 1e-4), key);
+    // q and -q are the same rotation
+    let flipped = Isometry3::from_parts(
+        iso.translation,
+        Quaternion::new_unchecked(-iso.rotation.into_inner()),
+    );
+    assert_eq!(QuantizedPose::new(&flipped, 1e-3, 1e-4), key);
+
+    let moved = Isometry3::new(Vector3::new(1.0, 2.0, 3.01), Vector3::new(0.1, 0.2, 0.3));
+    assert_ne!(QuantizedPose::new(&moved, 1e-3, 1e-4), key);
+    let turned = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.301));
+    assert_ne!(QuantizedPose::new(&turned, 1e-3, 1e-4), key);
+
+    let mut cache = std::collections::HashMap::new();
+    cache.insert(key, "cached");
+    assert_eq!(
+        cache.get(&QuantizedPose::new(&nudged, 1e-3, 1e-4)),
+        Some(&"cached")
+    );
+    assert_eq!(cache.get(&QuantizedPose::new(&moved, 1e-3, 1e-4)), None);
+}
+
+#[test]
+fn adjoint_of_the_identity_is_the_identity() {
+    assert_eq!(adjoint(&Isometry3::identity()), Matrix6::identity());
+}
+
+#[test]
+fn twists_round_trip_between_body_and_spatial_frames() {
+    let world_t_body = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, 0.2, -0.4));
+    let body = Twist::new(Vector3::new(0.5, 0.0, 0.1), Vector3::new(0.0, 0.2, 1.0));
+    let spatial = body.to_spatial(&world_t_body);
+    assert_relative_eq!(
+        spatial.to_body(&world_t_body).to_vector(),
+        body.to_vector(),
+        epsilon = DEFAULT_EPSILON
+    );
+
+    // the same motion either way, moving a little along the body twist is
+    // moving the same little along the spatial one from the world frame
+    let dt = 1e-3;
+    let by_body = world_t_body * exp(&(body.to_vector() * dt));
+    let by_spatial = exp(&(spatial.to_vector() * dt)) * world_t_body;
+    assert_iso_eq!(by_body, by_spatial);
+}
+
+#[test]
+fn adjoint_is_a_homomorphism() {
+    let a = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, 0.2, -0.4));
+    let b = Isometry3::new(Vector3::new(-0.5, 3.0, 1.0), Vector3::new(-1.0, 0.5, 0.7));
+    assert_relative_eq!(
+        adjoint(&a) * adjoint(&b),
+        adjoint(&(a * b)),
+        epsilon = DEFAULT_EPSILON
+    );
+    assert_relative_eq!(
+        adjoint(&a.inverse()),
+        adjoint(&a).try_inverse().unwrap(),
+        epsilon = DEFAULT_EPSILON
+    );
+    // and moves a tangent vector out of a's frame, as conjugation does
+    let xi = Vector6::new(0.1, 0.2, 0.3, 0.01, -0.02, 0.03);
+    assert_relative_eq!(
+        adjoint(&a) * xi,
+        log(&(a * exp(&xi) * a.inverse())),
+        epsilon = DEFAULT_EPSILON
+    );
+}
+
+#[test]
+fn format_pose_is_one_compact_line() {
+    let iso = from_pose_euler(
+        &[1.0, -2.5, 0.125],
+        &[0.1, -0.2, std::f64::consts::FRAC_PI_2],
+    );
+    assert_eq!(
+        format_pose(&iso),
+        "pos=(1.000, -2.500, 0.125) rpy=(0.100, -0.200, 1.571)"
+    );
+    assert_eq!(
+        format_pose(&Isometry3::identity()),
+        "pos=(0.000, 0.000, 0.000) rpy=(0.000, 0.000, 0.000)"
+    );
+}
+
+#[test]
+fn iso_abs_diff_eq_allows_epsilon_and_the_quaternion_sign() {
+    let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let flipped = Isometry3::from_parts(
+        iso.translation,
+        Quaternion::new_unchecked(-iso.rotation.into_inner()),
+    );
+    assert!(iso_abs_diff_eq(&iso, &flipped, 0.0));
+    let nudged = Isometry3::from_parts(
+        (iso.translation.vector + Vector3::x() * DEFAULT_EPSILON / 10.0).into(),
+        iso.rotation,
+    );
+    assert!(iso_abs_diff_eq(&iso, &nudged, DEFAULT_EPSILON));
+    let moved = Isometry3::from_parts(
+        (iso.translation.vector + Vector3::x() * DEFAULT_EPSILON * 10.0).into(),
+        iso.rotation,
+    );
+    assert!(!iso_abs_diff_eq(&iso, &moved, DEFAULT_EPSILON));
+    let turned = iso * Isometry3::rotation(Vector3::z() * DEFAULT_EPSILON * 100.0);
+    assert!(!iso_abs_diff_eq(&iso, &turned, DEFAULT_EPSILON));
+}